@@ -0,0 +1,104 @@
+//! Derive macros for `object-chain`. Use them through the `derive` feature of `object-chain`.
+//!
+//! This crate intentionally does not provide an attribute that registers types from multiple
+//! modules into a single chain. Procedural macros are expanded independently of each other, in an
+//! unspecified order, and can't inspect other modules, so collecting entries at compile time
+//! can't be implemented reliably. Instead, let every module export the chain of its own entries,
+//! and join them using `Concat` in one place.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, Index};
+
+/// Implements `ChainStruct` for a struct, converting its fields into a chain in declaration
+/// order.
+#[proc_macro_derive(Chain)]
+pub fn derive_chain(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "`Chain` can only be derived for structs",
+            ))
+        }
+    };
+
+    if fields.is_empty() {
+        return Err(Error::new(
+            input.span(),
+            "`Chain` can only be derived for structs with at least one field",
+        ));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Accessors of the fields on `self`, and the bindings used to destructure the chain.
+    let members = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = Index::from(index);
+                quote!(#index)
+            }
+        })
+        .collect::<Vec<_>>();
+    let bindings = (0..fields.len())
+        .map(|index| format_ident!("__field{}", index))
+        .collect::<Vec<_>>();
+
+    let mut types = fields.iter().map(|field| &field.ty);
+    let first = types.next().unwrap();
+    let chain_type = types.fold(
+        quote!(::object_chain::Chain<#first>),
+        |parent, ty| quote!(::object_chain::Link<#ty, #parent>),
+    );
+
+    let mut bindings_iter = bindings.iter();
+    let first = bindings_iter.next().unwrap();
+    let chain_pattern = bindings_iter.fold(
+        quote!(::object_chain::Chain { object: #first }),
+        |parent, binding| quote!(::object_chain::Link { parent: #parent, object: #binding }),
+    );
+
+    let construct = match fields {
+        Fields::Named(_) => quote!(Self { #(#members: #bindings),* }),
+        _ => quote!(Self(#(#bindings),*)),
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::object_chain::ChainStruct for #name #ty_generics #where_clause {
+            type AsChain = #chain_type;
+
+            #[inline]
+            fn into_chain(self) -> Self::AsChain {
+                ::object_chain::const_chain![#(self.#members),*]
+            }
+
+            #[inline]
+            fn from_chain(chain: Self::AsChain) -> Self {
+                let #chain_pattern = chain;
+                #construct
+            }
+        }
+
+        impl #impl_generics ::core::convert::From<#chain_type> for #name #ty_generics #where_clause {
+            #[inline]
+            fn from(chain: #chain_type) -> Self {
+                <Self as ::object_chain::ChainStruct>::from_chain(chain)
+            }
+        }
+    })
+}
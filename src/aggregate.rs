@@ -0,0 +1,233 @@
+//! Reducing numeric properties of chain objects.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A numeric property of objects, e.g. the stack or buffer size a component needs.
+///
+/// Metrics are usually unit structs that only name the property. Objects report their value of
+/// the property by implementing [`Measure`].
+pub trait Metric {
+    /// The type of the measured value.
+    type Value;
+}
+
+/// An object that has a value of the metric `K`.
+pub trait Measure<K: Metric> {
+    /// Returns the value of the metric.
+    fn measure(&self) -> K::Value;
+}
+
+/// An associative operation with an identity element, used to reduce the measured values.
+pub trait Monoid<T> {
+    /// Returns the identity element of the operation.
+    fn identity() -> T;
+
+    /// Combines two values.
+    fn combine(a: T, b: T) -> T;
+}
+
+/// Adds the values.
+pub struct Sum;
+
+/// Multiplies the values.
+pub struct Product;
+
+/// Selects the greatest value.
+pub struct Max;
+
+/// Selects the smallest value.
+pub struct Min;
+
+/// Combines the values using bitwise or.
+pub struct BitOr;
+
+/// Combines the values using bitwise and.
+pub struct BitAnd;
+
+macro_rules! impl_monoids {
+    ($($t:ty),*) => {
+        $(
+            impl Monoid<$t> for Sum {
+                #[inline]
+                fn identity() -> $t {
+                    0
+                }
+
+                #[inline]
+                fn combine(a: $t, b: $t) -> $t {
+                    a + b
+                }
+            }
+
+            impl Monoid<$t> for Product {
+                #[inline]
+                fn identity() -> $t {
+                    1
+                }
+
+                #[inline]
+                fn combine(a: $t, b: $t) -> $t {
+                    a * b
+                }
+            }
+
+            impl Monoid<$t> for Max {
+                #[inline]
+                fn identity() -> $t {
+                    <$t>::MIN
+                }
+
+                #[inline]
+                fn combine(a: $t, b: $t) -> $t {
+                    a.max(b)
+                }
+            }
+
+            impl Monoid<$t> for Min {
+                #[inline]
+                fn identity() -> $t {
+                    <$t>::MAX
+                }
+
+                #[inline]
+                fn combine(a: $t, b: $t) -> $t {
+                    a.min(b)
+                }
+            }
+
+            impl Monoid<$t> for BitOr {
+                #[inline]
+                fn identity() -> $t {
+                    0
+                }
+
+                #[inline]
+                fn combine(a: $t, b: $t) -> $t {
+                    a | b
+                }
+            }
+
+            impl Monoid<$t> for BitAnd {
+                #[inline]
+                fn identity() -> $t {
+                    !0
+                }
+
+                #[inline]
+                fn combine(a: $t, b: $t) -> $t {
+                    a & b
+                }
+            }
+        )*
+    };
+}
+
+impl_monoids!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// A chain of objects that all have a value of the metric `K`.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, Max, Measure, Metric, Sum};
+///
+/// struct StackSize;
+///
+/// impl Metric for StackSize {
+///     type Value = usize;
+/// }
+///
+/// struct Logger;
+/// struct Network {
+///     buffers: usize,
+/// }
+///
+/// impl Measure<StackSize> for Logger {
+///     fn measure(&self) -> usize {
+///         512
+///     }
+/// }
+///
+/// impl Measure<StackSize> for Network {
+///     fn measure(&self) -> usize {
+///         1024 + 256 * self.buffers
+///     }
+/// }
+///
+/// let components = Chain::new(Logger).append(Network { buffers: 2 });
+///
+/// assert_eq!(2048, components.aggregate::<StackSize, Sum>());
+/// assert_eq!(1536, components.aggregate::<StackSize, Max>());
+/// ```
+pub trait MeasureChain<K: Metric>: ChainElement {
+    /// Measures every object and folds the values using `f`, in the order the objects were
+    /// appended.
+    fn fold_measures<Acc, F>(&self, init: Acc, f: &mut F) -> Acc
+    where
+        F: FnMut(Acc, K::Value) -> Acc;
+}
+
+impl<K: Metric, V: Measure<K>> MeasureChain<K> for Chain<V> {
+    #[inline]
+    fn fold_measures<Acc, F>(&self, init: Acc, f: &mut F) -> Acc
+    where
+        F: FnMut(Acc, K::Value) -> Acc,
+    {
+        f(init, self.object.measure())
+    }
+}
+
+impl<K: Metric, V: Measure<K>, C: MeasureChain<K>> MeasureChain<K> for Link<V, C> {
+    #[inline]
+    fn fold_measures<Acc, F>(&self, init: Acc, f: &mut F) -> Acc
+    where
+        F: FnMut(Acc, K::Value) -> Acc,
+    {
+        let acc = self.parent.fold_measures(init, f);
+        f(acc, self.object.measure())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Flags;
+
+    impl Metric for Flags {
+        type Value = u8;
+    }
+
+    struct Feature(u8);
+
+    impl Measure<Flags> for Feature {
+        fn measure(&self) -> u8 {
+            self.0
+        }
+    }
+
+    #[test]
+    pub fn test_aggregate() {
+        let features = Chain::new(Feature(0b001))
+            .append(Feature(0b100))
+            .append(Feature(0b101));
+
+        assert_eq!(10, features.aggregate::<Flags, Sum>());
+        assert_eq!(20, features.aggregate::<Flags, Product>());
+        assert_eq!(5, features.aggregate::<Flags, Max>());
+        assert_eq!(1, features.aggregate::<Flags, Min>());
+        assert_eq!(0b101, features.aggregate::<Flags, BitOr>());
+        assert_eq!(0b000, features.aggregate::<Flags, BitAnd>());
+    }
+
+    #[test]
+    pub fn test_fold_measures_visits_objects_in_order() {
+        let features = Chain::new(Feature(1)).append(Feature(2)).append(Feature(3));
+
+        let digits = MeasureChain::<Flags>::fold_measures(&features, 0u32, &mut |acc, value| {
+            acc * 10 + value as u32
+        });
+
+        assert_eq!(123, digits);
+    }
+}
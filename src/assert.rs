@@ -0,0 +1,103 @@
+//! Comparing chains in tests.
+
+use core::fmt::Debug;
+
+use crate::{private, Chain, ChainElement, Link};
+
+impl<V: Debug + PartialEq<W>, W: Debug> private::ChainEq<Chain<W>> for Chain<V> {
+    #[inline]
+    fn first_mismatch<'a>(
+        &'a self,
+        other: &'a Chain<W>,
+    ) -> Option<(usize, &'a dyn Debug, &'a dyn Debug)> {
+        if self.object == other.object {
+            None
+        } else {
+            Some((0, &self.object, &other.object))
+        }
+    }
+}
+
+impl<V, W, C, D> private::ChainEq<Link<W, D>> for Link<V, C>
+where
+    V: Debug + PartialEq<W>,
+    W: Debug,
+    C: ChainElement + private::ChainEq<D>,
+    D: ChainElement,
+{
+    #[inline]
+    fn first_mismatch<'a>(
+        &'a self,
+        other: &'a Link<W, D>,
+    ) -> Option<(usize, &'a dyn Debug, &'a dyn Debug)> {
+        if let Some(mismatch) = self.parent.first_mismatch(&other.parent) {
+            Some(mismatch)
+        } else if self.object == other.object {
+            None
+        } else {
+            Some((C::LEN, &self.object, &other.object))
+        }
+    }
+}
+
+/// Asserts that two chains are equal, comparing their objects one by one.
+///
+/// The second argument is either a chain, or a parenthesized list of objects, in the order they
+/// were appended. The two sides must have the same length, and every object must be comparable
+/// with the object at the same position of the other side.
+///
+/// On failure, the macro panics with the index and the [`Debug`] representation of the first pair
+/// of objects that are not equal, instead of printing the whole nested chain.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{assert_chain_eq, Chain, ChainElement};
+///
+/// let chain = Chain::new(1u8).append("two").append(3.0f32);
+///
+/// assert_chain_eq!(chain, Chain::new(1u8).append("two").append(3.0f32));
+/// assert_chain_eq!(chain, (1, "two", 3.0));
+/// ```
+///
+/// [`Debug`]: core::fmt::Debug
+#[macro_export]
+macro_rules! assert_chain_eq {
+    ($left:expr, ($($right:expr),+ $(,)?) $(,)?) => {
+        $crate::assert_chain_eq!($left, $crate::const_chain![$($right),+])
+    };
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if let Some((index, left, right)) = $crate::__private::first_mismatch(left, right) {
+                    panic!(
+                        "assertion failed: `(left == right)` at object {}\n  left: `{:?}`,\n right: `{:?}`",
+                        index, left, right
+                    );
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement};
+
+    #[test]
+    pub fn test_assert_chain_eq() {
+        let chain = Chain::new(1u8).append(2u16).append('c');
+
+        assert_chain_eq!(chain, Chain::new(1u8).append(2u16).append('c'));
+        assert_chain_eq!(chain, (1, 2, 'c'));
+        assert_chain_eq!(Chain::new(5u8), (5,));
+    }
+
+    #[test]
+    #[should_panic(expected = "at object 1\n  left: `2`,\n right: `3`")]
+    pub fn test_assert_chain_eq_reports_first_mismatch() {
+        let chain = Chain::new(1u8).append(2u16).append('c');
+
+        assert_chain_eq!(chain, (1, 3, 'd'));
+    }
+}
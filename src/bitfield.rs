@@ -0,0 +1,239 @@
+//! Packing chains of bit fields into register values.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A value that occupies a fixed number of bits of a register.
+///
+/// Chains of `BitField` values implement `BitField` themselves, so register layouts can be
+/// composed from smaller layouts.
+pub trait BitField {
+    /// The number of bits the value occupies.
+    const WIDTH: u32;
+
+    /// Returns the bits of the value. Bits above [`BitField::WIDTH`] are ignored.
+    fn to_bits(&self) -> u32;
+
+    /// Creates a value from the lowest [`BitField::WIDTH`] bits of `bits`. The other bits are
+    /// always zero.
+    fn from_bits(bits: u32) -> Self;
+}
+
+macro_rules! impl_bit_field {
+    ($($ty:ty: $width:expr),*) => {
+        $(
+            impl BitField for $ty {
+                const WIDTH: u32 = $width;
+
+                #[inline]
+                fn to_bits(&self) -> u32 {
+                    *self as u32
+                }
+
+                #[inline]
+                fn from_bits(bits: u32) -> Self {
+                    bits as $ty
+                }
+            }
+        )*
+    };
+}
+
+impl_bit_field!(u8: 8, u16: 16, u32: 32);
+
+impl BitField for bool {
+    const WIDTH: u32 = 1;
+
+    #[inline]
+    fn to_bits(&self) -> u32 {
+        *self as u32
+    }
+
+    #[inline]
+    fn from_bits(bits: u32) -> Self {
+        bits != 0
+    }
+}
+
+#[inline]
+const fn mask(width: u32) -> u32 {
+    match 1u32.checked_shl(width) {
+        Some(bit) => bit - 1,
+        None => u32::MAX,
+    }
+}
+
+#[inline]
+fn field_bits<V: BitField>(object: &V, offset: u32) -> u32 {
+    (object.to_bits() & mask(V::WIDTH))
+        .checked_shl(offset)
+        .unwrap_or(0)
+}
+
+#[inline]
+fn field_from<V: BitField>(bits: u32, offset: u32) -> V {
+    V::from_bits(bits.checked_shr(offset).unwrap_or(0) & mask(V::WIDTH))
+}
+
+/// A chain of [`BitField`]s that describes the layout of a register.
+///
+/// The fields are laid out in the order they were appended, starting at the least significant
+/// bit. The total width of the fields must not exceed 32 bits, which is checked at compile time.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{BitField, BitFieldChain, Chain, ChainElement, Link};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Mode {
+///     Idle,
+///     Run,
+///     Sleep,
+/// }
+///
+/// impl BitField for Mode {
+///     const WIDTH: u32 = 2;
+///
+///     fn to_bits(&self) -> u32 {
+///         match self {
+///             Mode::Idle => 0,
+///             Mode::Run => 1,
+///             Mode::Sleep => 2,
+///         }
+///     }
+///
+///     fn from_bits(bits: u32) -> Self {
+///         match bits {
+///             1 => Mode::Run,
+///             2 => Mode::Sleep,
+///             _ => Mode::Idle,
+///         }
+///     }
+/// }
+///
+/// type Control = Link<u8, Link<Mode, Chain<bool>>>;
+///
+/// let control: Control = Chain::new(true).append(Mode::Sleep).append(0x40);
+///
+/// assert_eq!(11, Control::TOTAL_WIDTH);
+/// assert_eq!(0b0100_0000_10_1, control.pack());
+/// assert_eq!(control, Control::unpack(0b0100_0000_10_1));
+/// ```
+pub trait BitFieldChain: ChainElement + Sized {
+    /// The sum of the widths of the fields.
+    const TOTAL_WIDTH: u32;
+
+    /// Packs the fields into a register value.
+    fn pack(&self) -> u32;
+
+    /// Unpacks the fields from a register value. Bits above [`BitFieldChain::TOTAL_WIDTH`] are
+    /// ignored.
+    fn unpack(bits: u32) -> Self;
+}
+
+impl<V: BitField> BitFieldChain for Chain<V> {
+    const TOTAL_WIDTH: u32 = V::WIDTH;
+
+    #[inline]
+    fn pack(&self) -> u32 {
+        const { assert!(Self::TOTAL_WIDTH <= 32, "the fields don't fit into 32 bits") };
+
+        field_bits(&self.object, 0)
+    }
+
+    #[inline]
+    fn unpack(bits: u32) -> Self {
+        const { assert!(Self::TOTAL_WIDTH <= 32, "the fields don't fit into 32 bits") };
+
+        Chain::new(field_from(bits, 0))
+    }
+}
+
+impl<V: BitField, C: BitFieldChain> BitFieldChain for Link<V, C> {
+    const TOTAL_WIDTH: u32 = C::TOTAL_WIDTH + V::WIDTH;
+
+    #[inline]
+    fn pack(&self) -> u32 {
+        const { assert!(Self::TOTAL_WIDTH <= 32, "the fields don't fit into 32 bits") };
+
+        self.parent.pack() | field_bits(&self.object, C::TOTAL_WIDTH)
+    }
+
+    #[inline]
+    fn unpack(bits: u32) -> Self {
+        const { assert!(Self::TOTAL_WIDTH <= 32, "the fields don't fit into 32 bits") };
+
+        Link {
+            parent: C::unpack(bits),
+            object: field_from(bits, C::TOTAL_WIDTH),
+        }
+    }
+}
+
+impl<V: BitField> BitField for Chain<V> {
+    const WIDTH: u32 = <Self as BitFieldChain>::TOTAL_WIDTH;
+
+    #[inline]
+    fn to_bits(&self) -> u32 {
+        self.pack()
+    }
+
+    #[inline]
+    fn from_bits(bits: u32) -> Self {
+        Self::unpack(bits)
+    }
+}
+
+impl<V: BitField, C: BitFieldChain> BitField for Link<V, C> {
+    const WIDTH: u32 = <Self as BitFieldChain>::TOTAL_WIDTH;
+
+    #[inline]
+    fn to_bits(&self) -> u32 {
+        self.pack()
+    }
+
+    #[inline]
+    fn from_bits(bits: u32) -> Self {
+        Self::unpack(bits)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_pack_unpack() {
+        let register = Chain::new(0xABu8).append(true).append(0x1234u16);
+
+        assert_eq!(25, <Link<u16, Link<bool, Chain<u8>>>>::TOTAL_WIDTH);
+        assert_eq!(0x0024_69AB, register.pack());
+        assert_eq!(register, BitFieldChain::unpack(0xFE24_69AB));
+    }
+
+    #[test]
+    pub fn test_nested_layouts() {
+        struct Nibble(u8);
+
+        impl BitField for Nibble {
+            const WIDTH: u32 = 4;
+
+            fn to_bits(&self) -> u32 {
+                self.0 as u32
+            }
+
+            fn from_bits(bits: u32) -> Self {
+                Nibble(bits as u8)
+            }
+        }
+
+        let register = Chain::new(Chain::new(Nibble(0x1F)).append(true)).append(0xFFu8);
+
+        assert_eq!(0x1FFF, register.pack());
+
+        let unpacked = <Link<u8, Chain<Link<bool, Chain<Nibble>>>>>::unpack(0x3E5);
+        assert_eq!(5, unpacked.parent.object.parent.object.0);
+        assert!(!unpacked.parent.object.object);
+        assert_eq!(0x1F, unpacked.object);
+    }
+}
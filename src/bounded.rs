@@ -0,0 +1,94 @@
+//! Enforcing a maximum chain length at compile time.
+
+use core::ops::{Deref, DerefMut};
+
+use crate::{ChainElement, Link};
+
+/// A chain that may contain at most `MAX` objects.
+///
+/// Appending to a `BoundedChain` fails to compile once the chain would exceed `MAX` objects,
+/// which can be used to cap the size of dispatch tables built from chains. The wrapper
+/// dereferences to the chain, so every other operation is available as usual.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{BoundedChain, Chain, ChainElement};
+///
+/// let chain = BoundedChain::<_, 2>::new(Chain::new(1u8)).append(2u16);
+///
+/// assert_eq!(2, chain.len());
+/// ```
+///
+/// ```rust,compile_fail
+/// use object_chain::{BoundedChain, Chain, ChainElement};
+///
+/// let chain = BoundedChain::<_, 2>::new(Chain::new(1u8))
+///     .append(2u16)
+///     .append(3u32);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoundedChain<C, const MAX: usize> {
+    chain: C,
+}
+
+impl<C: ChainElement, const MAX: usize> BoundedChain<C, MAX> {
+    /// Wraps `chain`, which must not contain more than `MAX` objects.
+    #[inline]
+    pub const fn new(chain: C) -> Self {
+        const {
+            assert!(C::LEN <= MAX, "the chain is longer than its maximum length");
+        }
+
+        Self { chain }
+    }
+
+    /// Appends an object to the chain, if the chain does not exceed `MAX` objects with it.
+    #[inline]
+    pub fn append<T>(self, item: T) -> BoundedChain<Link<T, C>, MAX> {
+        BoundedChain::new(self.chain.append(item))
+    }
+
+    /// Returns the wrapped chain.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.chain
+    }
+}
+
+impl<C, const MAX: usize> Deref for BoundedChain<C, MAX> {
+    type Target = C;
+
+    #[inline]
+    fn deref(&self) -> &C {
+        &self.chain
+    }
+}
+
+impl<C, const MAX: usize> DerefMut for BoundedChain<C, MAX> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut C {
+        &mut self.chain
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Chain;
+
+    #[test]
+    pub fn test_bounded_chain() {
+        let mut chain = BoundedChain::<_, 3>::new(Chain::new(1u8))
+            .append(2u16)
+            .append(3u32);
+
+        chain.object += 1;
+
+        assert_eq!(3, chain.len());
+        assert_eq!(
+            Chain::new(1u8).append(2u16).append(4u32),
+            chain.into_inner()
+        );
+    }
+}
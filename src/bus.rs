@@ -0,0 +1,148 @@
+//! Driving devices that share a bus.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A device attached to a bus of type `BUS`, e.g. an I2C sensor.
+///
+/// The device doesn't own the bus, so multiple devices can be driven through the same bus by
+/// passing it to every device in turn.
+pub trait BusDevice<BUS: ?Sized> {
+    /// Initializes the device.
+    fn init(&mut self, bus: &mut BUS);
+
+    /// Polls the device, e.g. to read a new measurement.
+    fn poll(&mut self, bus: &mut BUS);
+}
+
+/// A chain of [`BusDevice`] objects that share a bus of type `BUS`.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{BusDevice, BusDeviceChain, Chain, ChainElement};
+///
+/// struct I2c {
+///     transfers: u32,
+/// }
+///
+/// struct Thermometer {
+///     celsius: i16,
+/// }
+///
+/// struct Barometer {
+///     pascal: u32,
+/// }
+///
+/// impl BusDevice<I2c> for Thermometer {
+///     fn init(&mut self, bus: &mut I2c) {
+///         bus.transfers += 1;
+///     }
+///
+///     fn poll(&mut self, bus: &mut I2c) {
+///         bus.transfers += 1;
+///         self.celsius = 21;
+///     }
+/// }
+///
+/// impl BusDevice<I2c> for Barometer {
+///     fn init(&mut self, bus: &mut I2c) {
+///         bus.transfers += 2;
+///     }
+///
+///     fn poll(&mut self, bus: &mut I2c) {
+///         bus.transfers += 1;
+///         self.pascal = 101_325;
+///     }
+/// }
+///
+/// let mut bus = I2c { transfers: 0 };
+/// let mut sensors = Chain::new(Thermometer { celsius: 0 }).append(Barometer { pascal: 0 });
+///
+/// sensors.init_all(&mut bus);
+/// sensors.poll_all(&mut bus);
+///
+/// assert_eq!(5, bus.transfers);
+/// assert_eq!(101_325, sensors.object.pascal);
+/// ```
+pub trait BusDeviceChain<BUS: ?Sized>: ChainElement {
+    /// Initializes every device, in the order they were appended.
+    fn init_all(&mut self, bus: &mut BUS);
+
+    /// Polls every device, in the order they were appended.
+    fn poll_all(&mut self, bus: &mut BUS);
+}
+
+impl<BUS: ?Sized, D: BusDevice<BUS>> BusDeviceChain<BUS> for Chain<D> {
+    #[inline]
+    fn init_all(&mut self, bus: &mut BUS) {
+        self.object.init(bus);
+    }
+
+    #[inline]
+    fn poll_all(&mut self, bus: &mut BUS) {
+        self.object.poll(bus);
+    }
+}
+
+impl<BUS: ?Sized, D: BusDevice<BUS>, C: BusDeviceChain<BUS>> BusDeviceChain<BUS> for Link<D, C> {
+    #[inline]
+    fn init_all(&mut self, bus: &mut BUS) {
+        self.parent.init_all(bus);
+        self.object.init(bus);
+    }
+
+    #[inline]
+    fn poll_all(&mut self, bus: &mut BUS) {
+        self.parent.poll_all(bus);
+        self.object.poll(bus);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Bus {
+        log: [u8; 4],
+        len: usize,
+    }
+
+    impl Bus {
+        fn record(&mut self, address: u8) {
+            self.log[self.len] = address;
+            self.len += 1;
+        }
+    }
+
+    struct Device<const ADDRESS: u8> {
+        polls: u32,
+    }
+
+    impl<const ADDRESS: u8> BusDevice<Bus> for Device<ADDRESS> {
+        fn init(&mut self, bus: &mut Bus) {
+            bus.record(ADDRESS);
+        }
+
+        fn poll(&mut self, bus: &mut Bus) {
+            bus.record(ADDRESS);
+            self.polls += 1;
+        }
+    }
+
+    #[test]
+    pub fn test_bus_devices() {
+        let mut bus = Bus {
+            log: [0; 4],
+            len: 0,
+        };
+        let mut devices =
+            Chain::new(Device::<0x48> { polls: 0 }).append(Device::<0x76> { polls: 0 });
+
+        devices.init_all(&mut bus);
+        devices.poll_all(&mut bus);
+
+        assert_eq!([0x48, 0x76, 0x48, 0x76], bus.log);
+        assert_eq!(1, devices.parent.object.polls);
+        assert_eq!(1, devices.object.polls);
+    }
+}
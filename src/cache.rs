@@ -0,0 +1,185 @@
+//! Tiered caches.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A single tier of a cache.
+pub trait CacheTier<K, V> {
+    /// Returns the value stored for `key`, or `None` if this tier doesn't contain `key`.
+    fn get(&mut self, key: &K) -> Option<V>;
+
+    /// Stores `value` for `key`, and returns the entry that had to be evicted to make room for it.
+    fn put(&mut self, key: K, value: V) -> Option<(K, V)>;
+}
+
+/// A chain of [`CacheTier`] objects.
+///
+/// The tiers are searched in the order they were appended, so the chain should be built starting
+/// with the fastest tier. Entries that are evicted from a tier are moved into the next one.
+pub trait CacheChain<K, V>: ChainElement {
+    /// Returns the value stored for `key` in the first tier that contains `key`.
+    fn fetch(&mut self, key: &K) -> Option<V>;
+
+    /// Returns the value stored for `key` in the first tier that contains `key`, and copies the
+    /// entry into the first tier.
+    ///
+    /// Also returns the entry that was evicted from the last tier during the promotion.
+    fn fetch_promote(&mut self, key: &K) -> (Option<V>, Option<(K, V)>)
+    where
+        K: Clone,
+        V: Clone;
+
+    /// Stores `value` for `key` in the first tier.
+    ///
+    /// Returns the entry that was evicted from the last tier.
+    fn store(&mut self, key: K, value: V) -> Option<(K, V)>;
+}
+
+impl<K, V, T> CacheChain<K, V> for Chain<T>
+where
+    T: CacheTier<K, V>,
+{
+    #[inline]
+    fn fetch(&mut self, key: &K) -> Option<V> {
+        self.object.get(key)
+    }
+
+    #[inline]
+    fn fetch_promote(&mut self, key: &K) -> (Option<V>, Option<(K, V)>)
+    where
+        K: Clone,
+        V: Clone,
+    {
+        (self.object.get(key), None)
+    }
+
+    #[inline]
+    fn store(&mut self, key: K, value: V) -> Option<(K, V)> {
+        self.object.put(key, value)
+    }
+}
+
+impl<K, V, T, C> CacheChain<K, V> for Link<T, C>
+where
+    T: CacheTier<K, V>,
+    C: CacheChain<K, V>,
+{
+    #[inline]
+    fn fetch(&mut self, key: &K) -> Option<V> {
+        self.parent.fetch(key).or_else(|| self.object.get(key))
+    }
+
+    fn fetch_promote(&mut self, key: &K) -> (Option<V>, Option<(K, V)>)
+    where
+        K: Clone,
+        V: Clone,
+    {
+        let (value, evicted) = self.parent.fetch_promote(key);
+        let evicted = evicted.and_then(|(key, value)| self.object.put(key, value));
+
+        if value.is_some() {
+            return (value, evicted);
+        }
+
+        match self.object.get(key) {
+            Some(value) => {
+                let evicted = self
+                    .parent
+                    .store(key.clone(), value.clone())
+                    .and_then(|(key, value)| self.object.put(key, value));
+
+                (Some(value), evicted)
+            }
+            None => (None, evicted),
+        }
+    }
+
+    #[inline]
+    fn store(&mut self, key: K, value: V) -> Option<(K, V)> {
+        self.parent
+            .store(key, value)
+            .and_then(|(key, value)| self.object.put(key, value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A cache tier that evicts the oldest entry.
+    struct Fifo<const N: usize> {
+        entries: [Option<(u8, u32)>; N],
+        next: usize,
+    }
+
+    impl<const N: usize> Fifo<N> {
+        fn new() -> Self {
+            Self {
+                entries: [None; N],
+                next: 0,
+            }
+        }
+    }
+
+    impl<const N: usize> CacheTier<u8, u32> for Fifo<N> {
+        fn get(&mut self, key: &u8) -> Option<u32> {
+            self.entries
+                .iter()
+                .flatten()
+                .find(|(k, _)| k == key)
+                .map(|(_, value)| *value)
+        }
+
+        fn put(&mut self, key: u8, value: u32) -> Option<(u8, u32)> {
+            let evicted = self.entries[self.next].replace((key, value));
+            self.next = (self.next + 1) % N;
+            evicted
+        }
+    }
+
+    /// The slowest tier that contains every value.
+    struct Backing {
+        reads: u32,
+    }
+
+    impl CacheTier<u8, u32> for Backing {
+        fn get(&mut self, key: &u8) -> Option<u32> {
+            self.reads += 1;
+            Some(*key as u32 * 10)
+        }
+
+        fn put(&mut self, _key: u8, _value: u32) -> Option<(u8, u32)> {
+            None
+        }
+    }
+
+    #[test]
+    pub fn test_store_evicts_into_later_tiers() {
+        let mut cache = Chain::new(Fifo::<1>::new()).append(Fifo::<1>::new());
+
+        assert_eq!(None, cache.store(1, 10));
+        assert_eq!(None, cache.store(2, 20));
+        assert_eq!(Some((1, 10)), cache.store(3, 30));
+
+        assert_eq!(Some(30), cache.parent.object.get(&3));
+        assert_eq!(Some(20), cache.object.get(&2));
+        assert_eq!(None, cache.fetch(&1));
+    }
+
+    #[test]
+    pub fn test_fetch_promote() {
+        let mut cache = Chain::new(Fifo::<1>::new())
+            .append(Fifo::<2>::new())
+            .append(Backing { reads: 0 });
+
+        assert_eq!(Some(50), cache.fetch(&5));
+        assert_eq!(1, cache.object.reads);
+
+        assert_eq!((Some(50), None), cache.fetch_promote(&5));
+        assert_eq!((Some(50), None), cache.fetch_promote(&5));
+        assert_eq!(2, cache.object.reads);
+
+        assert_eq!((Some(60), None), cache.fetch_promote(&6));
+        assert_eq!(Some(50), cache.parent.object.get(&5));
+        assert_eq!(Some(60), cache.parent.parent.object.get(&6));
+    }
+}
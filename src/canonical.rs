@@ -0,0 +1,215 @@
+//! Sorting the objects of a chain into a canonical order.
+
+use crate::{cmp, private, Chain, ChainElement, Compare, Link, Nat};
+
+/// Assigns a type-level sort key to a type, used to sort chains with [`Canonicalize`].
+///
+/// Every object type of a sorted chain must state its key, see
+/// [type-level limitations](crate#type-level-limitations). Use [`Const`](crate::Const) to convert
+/// an integer into a key.
+pub trait SortKey {
+    /// The key of the type.
+    type Key: Nat;
+}
+
+/// Inserts `V` into a sorted chain, after the objects whose keys are less than or equal to the key
+/// of `V`.
+pub trait Insert<V> {
+    /// The chain with `V` inserted.
+    type Inserted: ChainElement;
+
+    /// Inserts `object` into the chain.
+    fn insert_sorted(self, object: V) -> Self::Inserted;
+}
+
+impl<V, W> Insert<V> for Chain<W>
+where
+    V: SortKey,
+    W: SortKey,
+    V::Key: Compare<W::Key>,
+    Self: private::InsertOrdering<V, <V::Key as Compare<W::Key>>::Ordering>,
+{
+    type Inserted =
+        <Self as private::InsertOrdering<V, <V::Key as Compare<W::Key>>::Ordering>>::Inserted;
+
+    #[inline]
+    fn insert_sorted(self, object: V) -> Self::Inserted {
+        private::InsertOrdering::insert_ordered(self, object)
+    }
+}
+
+impl<V, W, C> Insert<V> for Link<W, C>
+where
+    V: SortKey,
+    W: SortKey,
+    C: ChainElement,
+    V::Key: Compare<W::Key>,
+    Self: private::InsertOrdering<V, <V::Key as Compare<W::Key>>::Ordering>,
+{
+    type Inserted =
+        <Self as private::InsertOrdering<V, <V::Key as Compare<W::Key>>::Ordering>>::Inserted;
+
+    #[inline]
+    fn insert_sorted(self, object: V) -> Self::Inserted {
+        private::InsertOrdering::insert_ordered(self, object)
+    }
+}
+
+// Objects with lesser keys go before the last object.
+impl<V, W> private::InsertOrdering<V, cmp::Less> for Chain<W> {
+    type Inserted = Link<W, Chain<V>>;
+
+    #[inline]
+    fn insert_ordered(self, object: V) -> Self::Inserted {
+        Chain::new(object).append(self.object)
+    }
+}
+
+impl<V, W, C> private::InsertOrdering<V, cmp::Less> for Link<W, C>
+where
+    C: ChainElement + Insert<V>,
+{
+    type Inserted = Link<W, C::Inserted>;
+
+    #[inline]
+    fn insert_ordered(self, object: V) -> Self::Inserted {
+        Link {
+            parent: self.parent.insert_sorted(object),
+            object: self.object,
+        }
+    }
+}
+
+// Objects with equal or greater keys go after the last object, which keeps the sort stable.
+macro_rules! impl_insert_last {
+    ($($ordering:ty),*) => {
+        $(
+            impl<V, W> private::InsertOrdering<V, $ordering> for Chain<W> {
+                type Inserted = Link<V, Chain<W>>;
+
+                #[inline]
+                fn insert_ordered(self, object: V) -> Self::Inserted {
+                    self.append(object)
+                }
+            }
+
+            impl<V, W, C: ChainElement> private::InsertOrdering<V, $ordering> for Link<W, C> {
+                type Inserted = Link<V, Link<W, C>>;
+
+                #[inline]
+                fn insert_ordered(self, object: V) -> Self::Inserted {
+                    self.append(object)
+                }
+            }
+        )*
+    };
+}
+
+impl_insert_last!(cmp::Equal, cmp::Greater);
+
+/// Sorts the objects of a chain by their [`SortKey`]s.
+///
+/// Chains that contain the same object types with distinct keys are sorted into the same type,
+/// regardless of the order the objects were appended in. Objects with equal keys keep the order
+/// they were appended in.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, Const, Link, SortKey, ToNat};
+///
+/// struct Uart(u32);
+/// struct Spi(u32);
+///
+/// impl SortKey for Uart {
+///     type Key = <Const<1> as ToNat>::Nat;
+/// }
+///
+/// impl SortKey for Spi {
+///     type Key = <Const<2> as ToNat>::Nat;
+/// }
+///
+/// type Drivers = Link<Spi, Chain<Uart>>;
+///
+/// fn init(drivers: Drivers) -> u32 {
+///     drivers.parent.object.0 + drivers.object.0
+/// }
+///
+/// let a = Chain::new(Uart(115_200)).append(Spi(1_000_000));
+/// let b = Chain::new(Spi(1_000_000)).append(Uart(115_200));
+///
+/// assert_eq!(init(a.canonicalize()), init(b.canonicalize()));
+/// ```
+pub trait Canonicalize {
+    /// The sorted chain.
+    type Canonical: ChainElement;
+
+    /// Sorts the objects of the chain.
+    fn into_canonical(self) -> Self::Canonical;
+}
+
+impl<V: SortKey> Canonicalize for Chain<V> {
+    type Canonical = Chain<V>;
+
+    #[inline]
+    fn into_canonical(self) -> Chain<V> {
+        self
+    }
+}
+
+impl<V, C> Canonicalize for Link<V, C>
+where
+    C: ChainElement + Canonicalize,
+    C::Canonical: Insert<V>,
+{
+    type Canonical = <C::Canonical as Insert<V>>::Inserted;
+
+    #[inline]
+    fn into_canonical(self) -> Self::Canonical {
+        self.parent.into_canonical().insert_sorted(self.object)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Const, ToNat};
+
+    #[derive(Debug, PartialEq)]
+    struct Keyed<const KEY: usize, const ID: u8>;
+
+    impl<const KEY: usize, const ID: u8> SortKey for Keyed<KEY, ID>
+    where
+        Const<KEY>: ToNat,
+    {
+        type Key = <Const<KEY> as ToNat>::Nat;
+    }
+
+    #[test]
+    pub fn test_canonicalize() {
+        let sorted: Link<Keyed<3, 0>, Link<Keyed<2, 1>, Chain<Keyed<1, 2>>>> =
+            Chain::new(Keyed::<3, 0>)
+                .append(Keyed::<2, 1>)
+                .append(Keyed::<1, 2>)
+                .canonicalize();
+
+        assert_eq!(3, sorted.len());
+    }
+
+    #[test]
+    pub fn test_canonicalize_is_stable() {
+        let sorted = Chain::new(Keyed::<2, 0>)
+            .append(Keyed::<1, 1>)
+            .append(Keyed::<2, 2>)
+            .append(Keyed::<1, 3>)
+            .canonicalize();
+
+        assert_eq!(
+            Chain::new(Keyed::<1, 1>)
+                .append(Keyed::<1, 3>)
+                .append(Keyed::<2, 0>)
+                .append(Keyed::<2, 2>),
+            sorted
+        );
+    }
+}
@@ -0,0 +1,138 @@
+//! Command registries, e.g. for serial consoles.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A named command that can be executed with arguments of type `A`, returning `R`.
+pub trait Command<A: ?Sized, R> {
+    /// Returns the name that selects this command.
+    fn name(&self) -> &str;
+
+    /// Returns a short description of the command.
+    fn help(&self) -> &str;
+
+    /// Executes the command.
+    fn execute(&mut self, args: &A) -> R;
+}
+
+/// A chain of [`Command`] objects.
+///
+/// If multiple commands have the same name, the last appended one is executed, so a chain of
+/// default commands can be extended with commands that override some of them.
+pub trait CommandChain<A: ?Sized, R>: ChainElement {
+    /// Executes the command called `name` with `args`.
+    ///
+    /// Returns the result of the command, or `None` if the chain has no command called `name`.
+    fn dispatch(&mut self, name: &str, args: &A) -> Option<R>;
+
+    /// Calls `visitor` with the name and help text of every command, in the order they were
+    /// appended.
+    fn for_each_help<'a, F: FnMut(&'a str, &'a str)>(&'a self, visitor: &mut F);
+}
+
+impl<A: ?Sized, R, T> CommandChain<A, R> for Chain<T>
+where
+    T: Command<A, R>,
+{
+    #[inline]
+    fn dispatch(&mut self, name: &str, args: &A) -> Option<R> {
+        if self.object.name() == name {
+            Some(self.object.execute(args))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn for_each_help<'a, F: FnMut(&'a str, &'a str)>(&'a self, visitor: &mut F) {
+        visitor(self.object.name(), self.object.help());
+    }
+}
+
+impl<A: ?Sized, R, T, C> CommandChain<A, R> for Link<T, C>
+where
+    T: Command<A, R>,
+    C: CommandChain<A, R>,
+{
+    #[inline]
+    fn dispatch(&mut self, name: &str, args: &A) -> Option<R> {
+        if self.object.name() == name {
+            Some(self.object.execute(args))
+        } else {
+            self.parent.dispatch(name, args)
+        }
+    }
+
+    #[inline]
+    fn for_each_help<'a, F: FnMut(&'a str, &'a str)>(&'a self, visitor: &mut F) {
+        self.parent.for_each_help(visitor);
+        visitor(self.object.name(), self.object.help());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Led(bool);
+
+    struct Echo;
+
+    impl Command<[&str], Result<usize, ()>> for Led {
+        fn name(&self) -> &str {
+            "led"
+        }
+
+        fn help(&self) -> &str {
+            "led on|off"
+        }
+
+        fn execute(&mut self, args: &[&str]) -> Result<usize, ()> {
+            match args {
+                ["on"] => self.0 = true,
+                ["off"] => self.0 = false,
+                _ => return Err(()),
+            }
+            Ok(0)
+        }
+    }
+
+    impl Command<[&str], Result<usize, ()>> for Echo {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn help(&self) -> &str {
+            "echo <args>"
+        }
+
+        fn execute(&mut self, args: &[&str]) -> Result<usize, ()> {
+            Ok(args.len())
+        }
+    }
+
+    #[test]
+    pub fn test_dispatch() {
+        let mut commands = Chain::new(Led(false)).append(Echo);
+
+        assert_eq!(Some(Ok(0)), commands.dispatch("led", &["on"]));
+        assert!(commands.parent.object.0);
+        assert_eq!(Some(Err(())), commands.dispatch("led", &[]));
+        assert_eq!(Some(Ok(2)), commands.dispatch("echo", &["a", "b"]));
+        assert_eq!(None, commands.dispatch("reset", &[]));
+    }
+
+    #[test]
+    pub fn test_for_each_help() {
+        let commands = Chain::new(Led(false)).append(Echo);
+
+        let mut lines = [""; 2];
+        let mut count = 0;
+        commands.for_each_help(&mut |name, help| {
+            lines[count] = help;
+            count += 1;
+            assert!(help.starts_with(name));
+        });
+
+        assert_eq!(["led on|off", "echo <args>"], lines);
+    }
+}
@@ -0,0 +1,143 @@
+//! Joining two chains together.
+
+use core::ops::Add;
+
+use crate::{Chain, ChainElement, Link};
+
+/// Implemented by chains that can be extended with the objects of the chain `Rhs`.
+///
+/// This is implemented for every pair of chains. Use [`ChainElement::concat`] or the `+` operator
+/// to join two chains.
+pub trait Concat<Rhs>: ChainElement {
+    /// The chain that contains the objects of `Self` followed by the objects of `Rhs`.
+    type Output: ChainElement;
+
+    /// Appends every object of `rhs` to this chain, in the order they were appended to `rhs`.
+    fn concat_chain(self, rhs: Rhs) -> Self::Output;
+}
+
+impl<S: ChainElement, V> Concat<Chain<V>> for S {
+    type Output = Link<V, S>;
+
+    #[inline]
+    fn concat_chain(self, rhs: Chain<V>) -> Self::Output {
+        self.append(rhs.object)
+    }
+}
+
+impl<S, V, C> Concat<Link<V, C>> for S
+where
+    S: Concat<C>,
+    C: ChainElement,
+{
+    type Output = Link<V, S::Output>;
+
+    #[inline]
+    fn concat_chain(self, rhs: Link<V, C>) -> Self::Output {
+        self.concat_chain(rhs.parent).append(rhs.object)
+    }
+}
+
+impl<V, Rhs> Add<Rhs> for Chain<V>
+where
+    Self: Concat<Rhs>,
+{
+    type Output = <Self as Concat<Rhs>>::Output;
+
+    #[inline]
+    fn add(self, rhs: Rhs) -> Self::Output {
+        self.concat_chain(rhs)
+    }
+}
+
+impl<V, C, Rhs> Add<Rhs> for Link<V, C>
+where
+    C: ChainElement,
+    Self: Concat<Rhs>,
+{
+    type Output = <Self as Concat<Rhs>>::Output;
+
+    #[inline]
+    fn add(self, rhs: Rhs) -> Self::Output {
+        self.concat_chain(rhs)
+    }
+}
+
+/// Implemented by chains that can be extended with the elements of the tuple `T`.
+///
+/// This is implemented for every chain and tuples of up to 12 elements. Use
+/// [`ChainElement::append_tuple`] to append the elements.
+pub trait AppendTuple<T>: ChainElement {
+    /// The chain that contains the objects of `Self` followed by the elements of `T`.
+    type Output: ChainElement;
+
+    /// Appends every element of `tuple` to this chain, in order.
+    fn append_elements(self, tuple: T) -> Self::Output;
+}
+
+impl<S: ChainElement> AppendTuple<()> for S {
+    type Output = S;
+
+    #[inline]
+    fn append_elements(self, _tuple: ()) -> S {
+        self
+    }
+}
+
+macro_rules! impl_append_tuple {
+    ($first:ident $(, $rest:ident)*) => {
+        impl<S: ChainElement, $first, $($rest),*> AppendTuple<($first, $($rest,)*)> for S {
+            type Output = <Link<$first, S> as AppendTuple<($($rest,)*)>>::Output;
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn append_elements(self, tuple: ($first, $($rest,)*)) -> Self::Output {
+                let ($first, $($rest,)*) = tuple;
+                self.append($first).append_elements(($($rest,)*))
+            }
+        }
+
+        impl_append_tuple!($($rest),*);
+    };
+    () => {};
+}
+
+impl_append_tuple!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10, T11, T12);
+
+#[cfg(test)]
+mod test {
+    use crate::{chain, Chain, ChainElement, Link};
+
+    #[test]
+    pub fn test_concat() {
+        let sensors = Chain::new(1u8).append(2u16);
+        let actuators = Chain::new(3u32).append(4u64).append(5i8);
+
+        let system: chain![u8, u16, u32, u64, i8] = sensors.concat(actuators);
+
+        assert_eq!(5, system.len());
+        assert_eq!(1, *system.at::<0>());
+        assert_eq!(3, *system.at::<2>());
+        assert_eq!(5, *system.at::<4>());
+    }
+
+    #[test]
+    pub fn test_append_tuple() {
+        let chain: chain![u8, u16, u32, char] = Chain::new(1u8).append_tuple((2u16, 3u32, 'a'));
+
+        assert_eq!(Chain::new(1u8).append(2u16).append(3u32).append('a'), chain);
+        assert_eq!(chain, chain.append_tuple(()));
+    }
+
+    #[test]
+    pub fn test_add() {
+        let sensors = Chain::new(1u8);
+        let actuators = Chain::new(2u16).append(3u32);
+
+        let system = sensors + actuators + Chain::new(4u64);
+
+        assert_eq!(4, system.len());
+        assert_eq!(2, *system.at::<1>());
+        assert_eq!(4, *system.at::<3>());
+    }
+}
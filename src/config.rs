@@ -0,0 +1,81 @@
+//! Layered configuration lookup.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A source of configuration values.
+pub trait ConfigSource<K: ?Sized, V> {
+    /// Returns the value stored for `key`, or `None` if this source doesn't contain `key`.
+    fn get(&self, key: &K) -> Option<V>;
+}
+
+/// A chain of [`ConfigSource`] objects.
+///
+/// Sources that were appended later take precedence over the ones appended earlier, so a chain
+/// is usually built starting with the default values, followed by the sources that override them.
+pub trait ConfigChain<K: ?Sized, V>: ChainElement {
+    /// Returns the value stored for `key` in the last appended source that contains `key`.
+    fn lookup(&self, key: &K) -> Option<V>;
+}
+
+impl<K: ?Sized, V, S> ConfigChain<K, V> for Chain<S>
+where
+    S: ConfigSource<K, V>,
+{
+    #[inline]
+    fn lookup(&self, key: &K) -> Option<V> {
+        self.object.get(key)
+    }
+}
+
+impl<K: ?Sized, V, S, C> ConfigChain<K, V> for Link<S, C>
+where
+    S: ConfigSource<K, V>,
+    C: ConfigChain<K, V>,
+{
+    #[inline]
+    fn lookup(&self, key: &K) -> Option<V> {
+        self.object.get(key).or_else(|| self.parent.lookup(key))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Defaults;
+
+    struct Overrides {
+        baud_rate: Option<u32>,
+    }
+
+    impl ConfigSource<str, u32> for Defaults {
+        fn get(&self, key: &str) -> Option<u32> {
+            match key {
+                "baud_rate" => Some(9600),
+                "timeout" => Some(100),
+                _ => None,
+            }
+        }
+    }
+
+    impl ConfigSource<str, u32> for Overrides {
+        fn get(&self, key: &str) -> Option<u32> {
+            match key {
+                "baud_rate" => self.baud_rate,
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_lookup() {
+        let mut chain = Chain::new(Defaults).append(Overrides { baud_rate: None });
+
+        assert_eq!(Some(9600), chain.lookup("baud_rate"));
+        assert_eq!(Some(100), chain.lookup("timeout"));
+        assert_eq!(None, chain.lookup("parity"));
+
+        chain.object.baud_rate = Some(115_200);
+        assert_eq!(Some(115_200), chain.lookup("baud_rate"));
+    }
+}
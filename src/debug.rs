@@ -0,0 +1,164 @@
+//! Printing the structure of a chain.
+
+use core::{
+    any,
+    fmt::{self, Debug, DebugList, Formatter},
+    marker::PhantomData,
+};
+
+use crate::{
+    private::{self, DebugObjects},
+    Chain, ChainElement, Link,
+};
+
+/// Prints the type names of the objects of a chain type, in the order they were appended.
+///
+/// The objects are not required to implement [`Debug`]. Use [`ChainElement::debug_types`] to
+/// create this adapter.
+///
+/// Note that the exact contents of the type names are not specified, see
+/// [`core::any::type_name`].
+pub struct DebugTypes<C> {
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<C> DebugTypes<C> {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: private::TypeNames> Debug for DebugTypes<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        C::list_type_names(&mut list);
+        list.finish()
+    }
+}
+
+/// Chains are formatted as a list of their objects, in the order they were appended.
+impl<V: Debug + ?Sized> Debug for Chain<V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        self.list_objects(&mut list);
+        list.finish()
+    }
+}
+
+impl<V: Debug + ?Sized, C: ChainElement + private::DebugObjects> Debug for Link<V, C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut list = f.debug_list();
+        self.list_objects(&mut list);
+        list.finish()
+    }
+}
+
+impl<V: Debug + ?Sized> private::DebugObjects for Chain<V> {
+    fn list_objects(&self, list: &mut DebugList<'_, '_>) {
+        list.entry(&&self.object);
+    }
+}
+
+impl<V: Debug + ?Sized, C: ChainElement + private::DebugObjects> private::DebugObjects
+    for Link<V, C>
+{
+    fn list_objects(&self, list: &mut DebugList<'_, '_>) {
+        self.parent.list_objects(list);
+        list.entry(&&self.object);
+    }
+}
+
+/// Formats a type name without quotes.
+struct TypeName(&'static str);
+
+impl Debug for TypeName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+impl<V> private::TypeNames for Chain<V> {
+    fn list_type_names(list: &mut DebugList<'_, '_>) {
+        list.entry(&TypeName(any::type_name::<V>()));
+    }
+
+    fn fill_type_names(names: &mut [&'static str]) {
+        names[0] = any::type_name::<V>();
+    }
+}
+
+impl<V, C: ChainElement + private::TypeNames> private::TypeNames for Link<V, C> {
+    fn list_type_names(list: &mut DebugList<'_, '_>) {
+        C::list_type_names(list);
+        list.entry(&TypeName(any::type_name::<V>()));
+    }
+
+    fn fill_type_names(names: &mut [&'static str]) {
+        C::fill_type_names(names);
+        names[C::LEN] = any::type_name::<V>();
+    }
+}
+
+/// Returns the type names of the objects of the chain `C`, in the order they were appended.
+///
+/// `N` must be equal to the length of the chain, which is checked at compile time. Like
+/// [`DebugTypes`], this doesn't require any trait implementations from the objects.
+///
+/// This function is not `const`, see [type-level limitations](crate#type-level-limitations).
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{chain, type_names, Chain, Link};
+///
+/// type Sensors = chain![u8, u16];
+///
+/// assert_eq!(["u8", "u16"], type_names::<Sensors, 2>());
+/// ```
+pub fn type_names<C: ChainElement + private::TypeNames, const N: usize>() -> [&'static str; N] {
+    const { assert!(N == C::LEN, "N must be equal to the length of the chain") };
+
+    let mut names = [""; N];
+    C::fill_type_names(&mut names);
+    names
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{type_names, Chain, ChainElement, Link};
+
+    extern crate std;
+    use std::format;
+
+    struct NotDebug;
+
+    #[test]
+    pub fn test_debug_types() {
+        let chain = Chain::new(1u8).append(NotDebug).append(2u32);
+
+        assert_eq!(
+            "[u8, object_chain::debug::test::NotDebug, u32]",
+            format!("{:?}", chain.debug_types())
+        );
+    }
+
+    #[test]
+    pub fn test_debug() {
+        let chain = Chain::new(1u8).append("two").append(Some(3));
+
+        assert_eq!("[1, \"two\", Some(3)]", format!("{:?}", chain));
+    }
+
+    #[test]
+    pub fn test_type_names() {
+        type Objects = Link<u32, Link<NotDebug, Chain<u8>>>;
+
+        assert_eq!(
+            ["u8", "object_chain::debug::test::NotDebug", "u32"],
+            type_names::<Objects, 3>()
+        );
+    }
+}
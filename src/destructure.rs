@@ -0,0 +1,105 @@
+//! Destructuring chains by position.
+
+/// Implementation of `chain_let!` and `match_chain!`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chain_pattern {
+    (@build [$($chain:tt)+];) => {
+        $($chain)+
+    };
+    (@build [$($chain:tt)+]; $object:pat $(, $rest:pat)*) => {
+        $crate::__chain_pattern!(@build [$crate::Link { parent: $($chain)+, object: $object }]; $($rest),*)
+    };
+    ($first:pat $(, $rest:pat)*) => {
+        $crate::__chain_pattern!(@build [$crate::Chain { object: $first }]; $($rest),*)
+    };
+}
+
+/// Destructures a chain into its objects, in the order they were appended.
+///
+/// The list of patterns must have exactly as many elements as the chain has objects. Every pattern
+/// is matched against the object at the same position, so the bindings have the concrete types of
+/// the objects. Destructuring a reference to a chain binds references to the objects.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{chain_let, Chain, ChainElement};
+///
+/// let mut chain = Chain::new(1u8).append("two").append(3.0f32);
+///
+/// chain_let!((_, name, value) = &mut chain);
+/// *value *= 2.0;
+/// assert_eq!("two", *name);
+///
+/// chain_let!((first, _, value) = chain);
+/// assert_eq!(1, first);
+/// assert_eq!(6.0, value);
+/// ```
+#[macro_export]
+macro_rules! chain_let {
+    (($($object:pat),+ $(,)?) = $chain:expr) => {
+        let $crate::__chain_pattern!($($object),+) = $chain;
+    };
+}
+
+/// Matches a chain against a list of arms, where each arm lists one pattern per object.
+///
+/// Like with [`chain_let!`], the patterns are matched against the objects in the order they were
+/// appended. Arms may have guards, and are tried in order, just like arms of a `match` expression.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{match_chain, Chain, ChainElement};
+///
+/// let chain = Chain::new(Some(3u8)).append(true);
+///
+/// let value = match_chain!(chain, {
+///     (Some(value), true) if value > 5 => value,
+///     (Some(value), true) => value * 2,
+///     (_, _) => 0,
+/// });
+/// assert_eq!(6, value);
+/// ```
+///
+/// [`chain_let!`]: crate::chain_let
+#[macro_export]
+macro_rules! match_chain {
+    ($chain:expr, { $(($($object:pat),+ $(,)?) $(if $guard:expr)? => $body:expr),+ $(,)? }) => {
+        match $chain {
+            $($crate::__chain_pattern!($($object),+) $(if $guard)? => $body,)+
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement};
+
+    #[test]
+    pub fn test_chain_let() {
+        let chain = Chain::new(1u8).append(2u16).append('c');
+
+        chain_let!((a, b, c) = &chain);
+        assert_eq!((1, 2, 'c'), (*a, *b, *c));
+
+        chain_let!((single) = Chain::new(5u32));
+        assert_eq!(5, single);
+    }
+
+    #[test]
+    pub fn test_match_chain() {
+        let classify = |chain: Chain<u8>| {
+            match_chain!(chain.append(0u16), {
+                (0, _) => "zero",
+                (n, _) if n % 2 == 0 => "even",
+                (_, _) => "odd",
+            })
+        };
+
+        assert_eq!("zero", classify(Chain::new(0)));
+        assert_eq!("even", classify(Chain::new(4)));
+        assert_eq!("odd", classify(Chain::new(7)));
+    }
+}
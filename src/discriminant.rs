@@ -0,0 +1,166 @@
+//! Dispatching to chain objects by a runtime ID.
+
+/// Assigns a constant ID to a type, used by [`dispatch_by_id!`] to select an object at runtime.
+///
+/// [`dispatch_by_id!`]: crate::dispatch_by_id
+pub trait Discriminant {
+    /// The ID of the type.
+    const ID: u16;
+}
+
+/// Implementation of `dispatch_by_id!`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dispatch_by_id {
+    ([$index:tt $($rest:tt)*] $mode:tt [$($path:tt)+] $id:tt |$elem:pat_param| $body:expr) => {
+        if $crate::__private::discriminant_of(&$($path)+.object) == $id {
+            $crate::__dispatch_by_id!(@arm $mode [$($path)+] |$elem| $body)
+        } else {
+            $crate::__dispatch_by_id!([$($rest)*] $mode [$($path)+ .parent] $id |$elem| $body)
+        }
+    };
+    ([] $mode:tt [$($path:tt)+] $id:tt |$elem:pat_param| $body:expr) => {
+        None
+    };
+    (@arm mut [$($path:tt)+] |$elem:pat_param| $body:expr) => {{
+        let $elem = &mut $($path)+.object;
+        Some($body)
+    }};
+    (@arm ref [$($path:tt)+] |$elem:pat_param| $body:expr) => {{
+        let $elem = &$($path)+.object;
+        Some($body)
+    }};
+}
+
+/// Runs an expression on the object whose [`Discriminant::ID`] matches a runtime ID.
+///
+/// Every object of the chain must implement [`Discriminant`]. The macro expands to a series of
+/// comparisons, where every branch binds the element to a reference of the object's concrete type,
+/// similar to [`static_dispatch!`].
+///
+/// The macro returns `Some` with the value of the expression, or `None` if no object has the given
+/// ID. If multiple objects share an ID, the last appended one is selected. The length of the chain
+/// has to be specified as an integer literal between 1 and 32, and the length is checked at compile
+/// time.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{dispatch_by_id, Chain, ChainElement, Discriminant};
+///
+/// trait Handler {
+///     fn handle(&mut self, payload: &[u8]) -> usize;
+/// }
+///
+/// struct Ping;
+/// struct Write(usize);
+///
+/// impl Discriminant for Ping {
+///     const ID: u16 = 0x01;
+/// }
+///
+/// impl Discriminant for Write {
+///     const ID: u16 = 0x10;
+/// }
+///
+/// impl Handler for Ping {
+///     fn handle(&mut self, _payload: &[u8]) -> usize {
+///         0
+///     }
+/// }
+///
+/// impl Handler for Write {
+///     fn handle(&mut self, payload: &[u8]) -> usize {
+///         self.0 += payload.len();
+///         payload.len()
+///     }
+/// }
+///
+/// let mut handlers = Chain::new(Ping).append(Write(0));
+///
+/// let message = (0x10, [1, 2, 3]);
+/// assert_eq!(
+///     Some(3),
+///     dispatch_by_id!(&mut handlers, 2, message.0, |handler| handler.handle(&message.1))
+/// );
+/// assert_eq!(None, dispatch_by_id!(&mut handlers, 2, 0x20, |handler| handler.handle(&[])));
+/// ```
+///
+/// [`static_dispatch!`]: crate::static_dispatch
+#[macro_export]
+macro_rules! dispatch_by_id {
+    (&mut $chain:expr, $len:tt, $id:expr, |$elem:pat_param| $body:expr) => {{
+        let chain = &mut $chain;
+        let id: u16 = $id;
+        $crate::__private::assert_exact_length::<_, $len>(&*chain);
+        $crate::__indices!($len, __dispatch_by_id, mut [chain] id |$elem| $body)
+    }};
+    (&$chain:expr, $len:tt, $id:expr, |$elem:pat_param| $body:expr) => {{
+        let chain = &$chain;
+        let id: u16 = $id;
+        $crate::__private::assert_exact_length::<_, $len>(chain);
+        $crate::__indices!($len, __dispatch_by_id, ref [chain] id |$elem| $body)
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement, Discriminant};
+
+    struct Status(u8);
+    struct Reset(bool);
+
+    impl Discriminant for Status {
+        const ID: u16 = 1;
+    }
+
+    impl Discriminant for Reset {
+        const ID: u16 = 2;
+    }
+
+    trait Handle {
+        fn handle(&mut self) -> u8;
+    }
+
+    impl Handle for Status {
+        fn handle(&mut self) -> u8 {
+            self.0
+        }
+    }
+
+    impl Handle for Reset {
+        fn handle(&mut self) -> u8 {
+            self.0 = true;
+            0
+        }
+    }
+
+    #[test]
+    pub fn test_dispatch_by_id() {
+        let mut chain = Chain::new(Status(5)).append(Reset(false));
+
+        assert_eq!(
+            Some(5),
+            dispatch_by_id!(&mut chain, 2, 1, |elem| elem.handle())
+        );
+        assert_eq!(
+            Some(0),
+            dispatch_by_id!(&mut chain, 2, 2, |elem| elem.handle())
+        );
+        assert_eq!(
+            None,
+            dispatch_by_id!(&mut chain, 2, 3, |elem| elem.handle())
+        );
+        assert!(chain.object.0);
+    }
+
+    #[test]
+    pub fn test_dispatch_by_id_duplicate() {
+        let mut chain = Chain::new(Status(1)).append(Reset(false)).append(Status(3));
+
+        assert_eq!(
+            Some(3),
+            dispatch_by_id!(&mut chain, 3, 1, |elem| elem.handle())
+        );
+    }
+}
@@ -0,0 +1,132 @@
+//! Collecting items from a chain of producers.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A producer of pending items, e.g. the consumer end of a queue, that can be drained into a
+/// common event type `E`.
+pub trait Drain<E> {
+    /// Removes the next pending item and converts it into an event, or returns `None` if there
+    /// are no pending items.
+    fn drain_one(&mut self) -> Option<E>;
+}
+
+/// A chain of [`Drain`] objects producing the same event type `E`.
+///
+/// This trait is implemented for every chain whose objects all implement [`Drain<E>`].
+pub trait DrainChain<E>: ChainElement {
+    /// Takes at most one item from every producer, in the order they were appended, and passes
+    /// them to `sink`. Returns the number of items taken.
+    fn drain_round(&mut self, sink: &mut impl FnMut(E)) -> usize;
+
+    /// Takes every pending item from every producer and passes them to `sink`. Returns the number
+    /// of items taken.
+    ///
+    /// Producers are visited in a round-robin fashion: every round takes at most one item from
+    /// every producer, so a busy producer can't starve the others. Draining stops after a round
+    /// that didn't take any items.
+    #[inline]
+    fn drain_all(&mut self, mut sink: impl FnMut(E)) -> usize {
+        let mut total = 0;
+        loop {
+            let drained = self.drain_round(&mut sink);
+            if drained == 0 {
+                return total;
+            }
+            total += drained;
+        }
+    }
+}
+
+impl<E, D: Drain<E>> DrainChain<E> for Chain<D> {
+    #[inline]
+    fn drain_round(&mut self, sink: &mut impl FnMut(E)) -> usize {
+        match self.object.drain_one() {
+            Some(event) => {
+                sink(event);
+                1
+            }
+            None => 0,
+        }
+    }
+}
+
+impl<E, D: Drain<E>, C: DrainChain<E>> DrainChain<E> for Link<D, C> {
+    #[inline]
+    fn drain_round(&mut self, sink: &mut impl FnMut(E)) -> usize {
+        let drained = self.parent.drain_round(sink);
+        match self.object.drain_one() {
+            Some(event) => {
+                sink(event);
+                drained + 1
+            }
+            None => drained,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Event {
+        Key(u8),
+        Tick(u32),
+    }
+
+    struct Keys {
+        pending: [u8; 3],
+        next: usize,
+    }
+
+    struct Timer {
+        ticks: u32,
+    }
+
+    impl Drain<Event> for Keys {
+        fn drain_one(&mut self) -> Option<Event> {
+            let key = *self.pending.get(self.next)?;
+            self.next += 1;
+            Some(Event::Key(key))
+        }
+    }
+
+    impl Drain<Event> for Timer {
+        fn drain_one(&mut self) -> Option<Event> {
+            if self.ticks == 0 {
+                return None;
+            }
+            self.ticks -= 1;
+            Some(Event::Tick(self.ticks))
+        }
+    }
+
+    #[test]
+    pub fn test_drain_all_round_robin() {
+        let mut chain = Chain::new(Keys {
+            pending: [1, 2, 3],
+            next: 0,
+        })
+        .append(Timer { ticks: 1 });
+
+        let mut events = [None, None, None, None, None];
+        let mut index = 0;
+        let drained = chain.drain_all(|event| {
+            events[index] = Some(event);
+            index += 1;
+        });
+
+        assert_eq!(4, drained);
+        assert_eq!(
+            [
+                Some(Event::Key(1)),
+                Some(Event::Tick(0)),
+                Some(Event::Key(2)),
+                Some(Event::Key(3)),
+                None,
+            ],
+            events
+        );
+        assert_eq!(0, chain.drain_all(|_| {}));
+    }
+}
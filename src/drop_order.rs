@@ -0,0 +1,135 @@
+//! Controlling the order in which the objects of a chain are dropped.
+
+use core::{
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+    ptr,
+};
+
+use crate::{private, Chain, ChainElement, Link};
+
+/// A chain whose objects are dropped starting with the last appended one.
+///
+/// Chains normally drop their objects in the order they were appended. Wrap a chain in
+/// `ReverseDrop` when the objects appended later depend on the ones appended before them, e.g.
+/// to stop consumers before the producers they read from. The wrapper dereferences to the chain.
+///
+/// # Example:
+///
+/// ```rust
+/// use core::cell::RefCell;
+/// use object_chain::{Chain, ChainElement, ReverseDrop};
+///
+/// struct Stage<'a>(&'static str, &'a RefCell<[&'static str; 2]>);
+///
+/// impl Drop for Stage<'_> {
+///     fn drop(&mut self) {
+///         let mut log = self.1.borrow_mut();
+///         let slot = if log[0].is_empty() { 0 } else { 1 };
+///         log[slot] = self.0;
+///     }
+/// }
+///
+/// let log = RefCell::new([""; 2]);
+/// let pipeline = Chain::new(Stage("producer", &log)).append(Stage("consumer", &log));
+/// drop(ReverseDrop::new(pipeline));
+///
+/// assert_eq!(["consumer", "producer"], *log.borrow());
+/// ```
+pub struct ReverseDrop<C: private::DropInReverse> {
+    chain: ManuallyDrop<C>,
+}
+
+impl<C: private::DropInReverse> ReverseDrop<C> {
+    /// Wraps `chain`.
+    #[inline]
+    pub const fn new(chain: C) -> Self {
+        Self {
+            chain: ManuallyDrop::new(chain),
+        }
+    }
+
+    /// Unwraps the chain, restoring its usual drop order.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        let mut this = ManuallyDrop::new(self);
+
+        // Safety: `this` is not dropped, so the chain is only moved out once.
+        unsafe { ManuallyDrop::take(&mut this.chain) }
+    }
+}
+
+impl<C: private::DropInReverse> Deref for ReverseDrop<C> {
+    type Target = C;
+
+    #[inline]
+    fn deref(&self) -> &C {
+        &self.chain
+    }
+}
+
+impl<C: private::DropInReverse> DerefMut for ReverseDrop<C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut C {
+        &mut self.chain
+    }
+}
+
+impl<C: private::DropInReverse> Drop for ReverseDrop<C> {
+    #[inline]
+    fn drop(&mut self) {
+        // Safety: the chain is not accessed after this point.
+        unsafe { self.chain.drop_in_reverse() };
+    }
+}
+
+impl<V> private::DropInReverse for Chain<V> {
+    #[inline]
+    unsafe fn drop_in_reverse(&mut self) {
+        ptr::drop_in_place(&mut self.object);
+    }
+}
+
+impl<V, C: ChainElement + private::DropInReverse> private::DropInReverse for Link<V, C> {
+    #[inline]
+    unsafe fn drop_in_reverse(&mut self) {
+        ptr::drop_in_place(&mut self.object);
+        self.parent.drop_in_reverse();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement, ReverseDrop};
+    use core::cell::Cell;
+
+    struct Logged<'a>(u32, &'a Cell<u32>);
+
+    impl Drop for Logged<'_> {
+        fn drop(&mut self) {
+            self.1.set(self.1.get() * 10 + self.0);
+        }
+    }
+
+    #[test]
+    pub fn test_drop_order() {
+        let log = Cell::new(0);
+        let chain = || {
+            Chain::new(Logged(1, &log))
+                .append(Logged(2, &log))
+                .append(Logged(3, &log))
+        };
+
+        chain().drop_front_to_back();
+        assert_eq!(123, log.replace(0));
+
+        chain().drop_back_to_front();
+        assert_eq!(321, log.replace(0));
+
+        drop(ReverseDrop::new(chain()));
+        assert_eq!(321, log.replace(0));
+
+        drop(ReverseDrop::new(chain()).into_inner());
+        assert_eq!(123, log.replace(0));
+    }
+}
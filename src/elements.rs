@@ -0,0 +1,219 @@
+//! Converting chain objects into values of a common enum type.
+
+use crate::{private, Chain, ChainElement, Link};
+
+/// An enum with a variant for every object of a chain, in the order the objects were appended.
+///
+/// Use [`chain_enum!`] to declare such an enum.
+///
+/// [`chain_enum!`]: crate::chain_enum
+pub trait ChainEnum: Sized {
+    /// The chain whose objects are represented by the variants.
+    type Chain: ChainElement;
+
+    /// An iterator over the objects of the chain, as variants of the enum.
+    type Elements: Iterator<Item = Self> + DoubleEndedIterator + ExactSizeIterator;
+
+    /// Returns the position of the object type that is stored in the variant.
+    fn index(&self) -> usize;
+
+    /// Consumes `chain` and returns an iterator that yields every object as a variant of the enum,
+    /// in the order they were appended.
+    ///
+    /// You will usually call this method through [`ChainElement::into_elements`].
+    fn split(chain: Self::Chain) -> Self::Elements;
+}
+
+impl<V: Into<E>, E> private::FillElements<E> for Chain<V> {
+    #[inline]
+    fn fill_elements(self, slots: &mut [Option<E>]) {
+        slots[0] = Some(self.object.into());
+    }
+}
+
+impl<V: Into<E>, C: ChainElement + private::FillElements<E>, E> private::FillElements<E>
+    for Link<V, C>
+{
+    #[inline]
+    fn fill_elements(self, slots: &mut [Option<E>]) {
+        let (parent, object) = slots.split_at_mut(C::LEN);
+        self.parent.fill_elements(parent);
+        object[0] = Some(self.object.into());
+    }
+}
+
+/// Implementation of `chain_enum!`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chain_enum {
+    (
+        [$(#[$attr:meta])*] $vis:vis $name:ident $chain:ty;
+        [$($variants:tt)*] [$($arms:tt)*] $position:ty;
+    ) => {
+        $(#[$attr])*
+        $vis enum $name {
+            $($variants)*
+        }
+
+        const _: () = assert!(
+            <$position as $crate::Nat>::VALUE == <$chain as $crate::ChainElement>::LEN,
+            "the enum must have a variant for every object of the chain"
+        );
+
+        impl $crate::ChainEnum for $name {
+            type Chain = $chain;
+            type Elements = ::core::array::IntoIter<Self, { <$chain as $crate::ChainElement>::LEN }>;
+
+            #[inline]
+            fn index(&self) -> usize {
+                match self {
+                    $($arms)*
+                }
+            }
+
+            #[inline]
+            fn split(chain: $chain) -> Self::Elements {
+                $crate::__private::split_into_elements(chain)
+            }
+        }
+    };
+    (
+        [$(#[$attr:meta])*] $vis:vis $name:ident $chain:ty;
+        [$($variants:tt)*] [$($arms:tt)*] $position:ty;
+        $(#[$variant_attr:meta])* $variant:ident($object:ty) $(, $($rest:tt)*)?
+    ) => {
+        const _: fn(<$chain as $crate::At<$position>>::Output) -> $object = |object| object;
+
+        impl From<$object> for $name {
+            #[inline]
+            fn from(object: $object) -> Self {
+                Self::$variant(object)
+            }
+        }
+
+        $crate::__chain_enum!(
+            [$(#[$attr])*] $vis $name $chain;
+            [
+                $($variants)*
+                $(#[$variant_attr])*
+                $variant($object),
+            ]
+            [
+                $($arms)*
+                Self::$variant(_) => <$position as $crate::Nat>::VALUE,
+            ] $crate::Succ<$position>;
+            $($($rest)*)?
+        );
+    };
+}
+
+/// Declares an enum with a variant for every object of an existing chain type.
+///
+/// The variants hold the types of the objects, in the order they were appended. This is useful to
+/// pass the objects of a chain to code that needs runtime-variant values, like queues or logs of
+/// heterogeneous entries. The macro implements [`ChainEnum`] for the enum, and `From` for each
+/// object type, so the object types must be distinct. Specifying a different number of variants
+/// than the length of the chain, or a type that doesn't match the object at the position of the
+/// variant, is a compile error.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{chain_enum, Chain, ChainElement, ChainEnum, Link};
+///
+/// pub struct Spi(u32);
+/// pub struct Uart(u32);
+///
+/// type Drivers = Link<Uart, Chain<Spi>>;
+///
+/// chain_enum! {
+///     /// A driver of the board.
+///     pub enum Driver(Drivers) {
+///         Spi(Spi),
+///         Uart(Uart),
+///     }
+/// }
+///
+/// let driver = Driver::from(Uart(115_200));
+/// assert_eq!(1, driver.index());
+/// assert!(matches!(driver, Driver::Uart(Uart(115_200))));
+///
+/// let drivers: Drivers = Chain::new(Spi(1_000_000)).append(Uart(9_600));
+/// for driver in drivers.into_elements::<Driver>() {
+///     match driver {
+///         Driver::Spi(spi) => assert_eq!(1_000_000, spi.0),
+///         Driver::Uart(uart) => assert_eq!(9_600, uart.0),
+///     }
+/// }
+/// ```
+///
+/// [`ChainEnum`]: crate::ChainEnum
+/// [`ChainElement::into_elements`]: crate::ChainElement::into_elements
+#[macro_export]
+macro_rules! chain_enum {
+    (
+        $(#[$attr:meta])*
+        $vis:vis enum $name:ident($chain:ty) {
+            $($(#[$variant_attr:meta])* $variant:ident($object:ty)),+ $(,)?
+        }
+    ) => {
+        $crate::__chain_enum!(
+            [$(#[$attr])*] $vis $name $chain;
+            [] [] $crate::Zero;
+            $($(#[$variant_attr])* $variant($object)),+
+        );
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement, ChainEnum, Link};
+
+    type Entries = Link<&'static str, Link<u16, Chain<u8>>>;
+
+    chain_enum! {
+        #[derive(Debug, PartialEq)]
+        enum Entry(Entries) {
+            Byte(u8),
+            Half(u16),
+            Text(&'static str),
+        }
+    }
+
+    #[test]
+    pub fn test_chain_enum() {
+        assert_eq!(Entry::Byte(1), Entry::from(1u8));
+        assert_eq!(Entry::Half(2), Entry::from(2u16));
+
+        assert_eq!(0, Entry::from(1u8).index());
+        assert_eq!(1, Entry::from(2u16).index());
+        assert_eq!(2, Entry::from("three").index());
+    }
+
+    #[test]
+    pub fn test_into_elements() {
+        let entries: Entries = Chain::new(1u8).append(2u16).append("three");
+
+        let mut elements = entries.into_elements::<Entry>();
+        assert_eq!(3, elements.len());
+        assert_eq!(Some(Entry::Byte(1)), elements.next());
+        assert_eq!(Some(Entry::Text("three")), elements.next_back());
+        assert_eq!(Some(Entry::Half(2)), elements.next());
+        assert_eq!(None, elements.next());
+    }
+
+    #[test]
+    pub fn test_collect_array() {
+        let numbers: [u32; 3] = Chain::new(1u8).append(2u16).append(3u32).collect_array();
+
+        assert_eq!([1, 2, 3], numbers);
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    pub fn test_collect_vec() {
+        let numbers: heapless::Vec<u32, 4> = Chain::new(1u8).append(2u16).collect_vec();
+
+        assert_eq!([1, 2], numbers[..]);
+    }
+}
@@ -0,0 +1,128 @@
+//! Arithmetic between the objects of two chains at the same positions.
+
+use core::ops;
+
+use crate::{AsRefs, Combine, ZipWith};
+
+/// A chain whose arithmetic operators apply to every object.
+///
+/// The operators combine the objects of two chains of the same length at the same positions, like
+/// [`ChainElement::zip_with`]. The objects of the two chains may have different types, as long as
+/// every pair supports the operator.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, Elementwise};
+///
+/// let mut readings = Elementwise(Chain::new(105i16).append(42i16).append(7i16));
+/// let offsets = Elementwise(Chain::new(-5i16).append(2i16).append(0i16));
+///
+/// readings += offsets;
+///
+/// let scaled = readings * Elementwise(Chain::new(2i16).append(1i16).append(3i16));
+///
+/// assert_eq!(Chain::new(200i16).append(44i16).append(21i16), scaled.0);
+/// ```
+///
+/// [`ChainElement::zip_with`]: crate::ChainElement::zip_with
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Elementwise<C>(pub C);
+
+impl<C> Elementwise<C> {
+    /// Returns the wrapped chain.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.0
+    }
+}
+
+macro_rules! impl_elementwise {
+    ($($op:ident, $method:ident, $each:ident, $assign_op:ident, $assign_method:ident, $assign_each:ident;)*) => {
+        $(
+            #[doc = concat!("Combines two objects using [`ops::", stringify!($op), "`]. Used by [`Elementwise`].")]
+            pub struct $each;
+
+            impl<A: ops::$op<B>, B> Combine<A, B> for $each {
+                type Output = A::Output;
+
+                #[inline]
+                fn combine(&mut self, a: A, b: B) -> A::Output {
+                    a.$method(b)
+                }
+            }
+
+            #[doc = concat!("Combines two objects using [`ops::", stringify!($assign_op), "`]. Used by [`Elementwise`].")]
+            pub struct $assign_each;
+
+            impl<A: ops::$assign_op<B>, B> Combine<&mut A, B> for $assign_each {
+                type Output = ();
+
+                #[inline]
+                fn combine(&mut self, a: &mut A, b: B) {
+                    a.$assign_method(b);
+                }
+            }
+
+            impl<A: ZipWith<B, $each>, B> ops::$op<Elementwise<B>> for Elementwise<A> {
+                type Output = Elementwise<A::Zipped>;
+
+                #[inline]
+                fn $method(self, rhs: Elementwise<B>) -> Self::Output {
+                    Elementwise(self.0.zip_with_combiner(rhs.0, &mut $each))
+                }
+            }
+
+            impl<A, B> ops::$assign_op<Elementwise<B>> for Elementwise<A>
+            where
+                A: for<'a> AsRefs<'a>,
+                for<'a> <A as AsRefs<'a>>::RefsMut: ZipWith<B, $assign_each>,
+            {
+                #[inline]
+                fn $assign_method(&mut self, rhs: Elementwise<B>) {
+                    self.0.borrow_each_mut().zip_with_combiner(rhs.0, &mut $assign_each);
+                }
+            }
+        )*
+    };
+}
+
+impl_elementwise! {
+    Add, add, AddEach, AddAssign, add_assign, AddAssignEach;
+    Sub, sub, SubEach, SubAssign, sub_assign, SubAssignEach;
+    Mul, mul, MulEach, MulAssign, mul_assign, MulAssignEach;
+    Div, div, DivEach, DivAssign, div_assign, DivAssignEach;
+    Rem, rem, RemEach, RemAssign, rem_assign, RemAssignEach;
+    BitAnd, bitand, BitAndEach, BitAndAssign, bitand_assign, BitAndAssignEach;
+    BitOr, bitor, BitOrEach, BitOrAssign, bitor_assign, BitOrAssignEach;
+    BitXor, bitxor, BitXorEach, BitXorAssign, bitxor_assign, BitXorAssignEach;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Chain, ChainElement};
+
+    #[test]
+    pub fn test_elementwise_ops() {
+        let a = Elementwise(Chain::new(10u8).append(20u16).append(0b1100u32));
+        let b = Elementwise(Chain::new(3u8).append(4u16).append(0b1010u32));
+
+        assert_eq!(Chain::new(13u8).append(24).append(0b10110), (a + b).0);
+        assert_eq!(Chain::new(7u8).append(16).append(0b0010), (a - b).0);
+        assert_eq!(Chain::new(1u8).append(0).append(0b0010), (a % b).0);
+        assert_eq!(Chain::new(2u8).append(4).append(0b1000), (a & b).0);
+        assert_eq!(Chain::new(9u8).append(16).append(0b0110), (a ^ b).0);
+    }
+
+    #[test]
+    pub fn test_elementwise_mixed_types() {
+        let offsets = Elementwise(Chain::new(1.5f32).append(2i8));
+        let mut values = Elementwise(Chain::new(1.0f32).append(3i8));
+
+        values -= offsets;
+        values *= offsets;
+
+        assert_eq!(Chain::new(-0.75f32).append(2i8), values.into_inner());
+    }
+}
@@ -0,0 +1,1005 @@
+//! Accessing chain elements through a common trait object type.
+
+use core::{
+    any::Any, convert::Infallible, iter::FusedIterator, marker::PhantomData, ops::ControlFlow,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{Chain, ChainElement, ChainError, KeyedChain, Link};
+
+/// Conversion from a concrete element type into the trait object type `Self`.
+///
+/// This trait is meant to be implemented for trait object types, e.g. `dyn MyTrait`. You will
+/// probably want to use the [`impl_erase!`] macro instead of implementing it by hand.
+///
+/// [`impl_erase!`]: crate::impl_erase
+pub trait Erase<T> {
+    /// Returns `item` as a reference to the trait object type.
+    fn erase(item: &T) -> &Self;
+
+    /// Returns `item` as a mutable reference to the trait object type.
+    fn erase_mut(item: &mut T) -> &mut Self;
+}
+
+/// Conversion from a boxed element into a boxed trait object.
+#[cfg(feature = "alloc")]
+pub trait EraseBox<T>: Erase<T> {
+    /// Returns `item` as a boxed trait object.
+    fn erase_box(item: Box<T>) -> Box<Self>;
+}
+
+/// Chains whose every element can be converted into the trait object type `D`.
+///
+/// This trait is implemented for every [`Chain`] and [`Link`] if `D` implements [`Erase`] for all
+/// the element types. You will usually use the methods of this trait through a [`DynView`].
+pub trait DynChain<D: ?Sized>: ChainElement {
+    /// Returns the element at `index`, or `None` if the index is out of bounds.
+    ///
+    /// Elements are indexed in the order they were appended.
+    fn get_dyn(&self, index: usize) -> Option<&D>;
+
+    /// Returns the element at `index` mutably, or `None` if the index is out of bounds.
+    ///
+    /// Elements are indexed in the order they were appended.
+    fn get_dyn_mut(&mut self, index: usize) -> Option<&mut D>;
+
+    /// Returns the element at `index`, or [`ChainError::OutOfBounds`] if the index is out of
+    /// bounds.
+    #[inline]
+    fn try_get_dyn(&self, index: usize) -> Result<&D, ChainError> {
+        self.get_dyn(index).ok_or(ChainError::OutOfBounds {
+            index,
+            len: Self::LEN,
+        })
+    }
+
+    /// Returns the element at `index` mutably, or [`ChainError::OutOfBounds`] if the index is out
+    /// of bounds.
+    #[inline]
+    fn try_get_dyn_mut(&mut self, index: usize) -> Result<&mut D, ChainError> {
+        self.get_dyn_mut(index).ok_or(ChainError::OutOfBounds {
+            index,
+            len: Self::LEN,
+        })
+    }
+
+    /// Folds every element, in the order they were appended, stopping at the first error.
+    fn try_fold_dyn<Acc, E, F>(&self, init: Acc, f: &mut F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, &D) -> Result<Acc, E>;
+
+    /// Folds every element mutably, in the order they were appended, stopping at the first error.
+    fn try_fold_dyn_mut<Acc, E, F>(&mut self, init: Acc, f: &mut F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, &mut D) -> Result<Acc, E>;
+
+    #[doc(hidden)]
+    fn fill_dyn<'a>(&'a self, slots: &mut [Option<&'a D>]);
+
+    #[doc(hidden)]
+    fn fill_dyn_mut<'a>(&'a mut self, slots: &mut [Option<&'a mut D>]);
+}
+
+impl<D, V> DynChain<D> for Chain<V>
+where
+    D: ?Sized + Erase<V>,
+{
+    #[inline]
+    fn get_dyn(&self, index: usize) -> Option<&D> {
+        if index == 0 {
+            Some(D::erase(&self.object))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn get_dyn_mut(&mut self, index: usize) -> Option<&mut D> {
+        if index == 0 {
+            Some(D::erase_mut(&mut self.object))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn try_fold_dyn<Acc, E, F>(&self, init: Acc, f: &mut F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, &D) -> Result<Acc, E>,
+    {
+        f(init, D::erase(&self.object))
+    }
+
+    #[inline]
+    fn try_fold_dyn_mut<Acc, E, F>(&mut self, init: Acc, f: &mut F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, &mut D) -> Result<Acc, E>,
+    {
+        f(init, D::erase_mut(&mut self.object))
+    }
+
+    #[inline]
+    fn fill_dyn<'a>(&'a self, slots: &mut [Option<&'a D>]) {
+        slots[0] = Some(D::erase(&self.object));
+    }
+
+    #[inline]
+    fn fill_dyn_mut<'a>(&'a mut self, slots: &mut [Option<&'a mut D>]) {
+        slots[0] = Some(D::erase_mut(&mut self.object));
+    }
+}
+
+impl<D, V, C> DynChain<D> for Link<V, C>
+where
+    D: ?Sized + Erase<V>,
+    C: DynChain<D>,
+{
+    #[inline]
+    fn get_dyn(&self, index: usize) -> Option<&D> {
+        if index == C::LEN {
+            Some(D::erase(&self.object))
+        } else {
+            self.parent.get_dyn(index)
+        }
+    }
+
+    #[inline]
+    fn get_dyn_mut(&mut self, index: usize) -> Option<&mut D> {
+        if index == C::LEN {
+            Some(D::erase_mut(&mut self.object))
+        } else {
+            self.parent.get_dyn_mut(index)
+        }
+    }
+
+    #[inline]
+    fn try_fold_dyn<Acc, E, F>(&self, init: Acc, f: &mut F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, &D) -> Result<Acc, E>,
+    {
+        let acc = self.parent.try_fold_dyn(init, f)?;
+        f(acc, D::erase(&self.object))
+    }
+
+    #[inline]
+    fn try_fold_dyn_mut<Acc, E, F>(&mut self, init: Acc, f: &mut F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, &mut D) -> Result<Acc, E>,
+    {
+        let acc = self.parent.try_fold_dyn_mut(init, f)?;
+        f(acc, D::erase_mut(&mut self.object))
+    }
+
+    #[inline]
+    fn fill_dyn<'a>(&'a self, slots: &mut [Option<&'a D>]) {
+        self.parent.fill_dyn(slots);
+        slots[C::LEN] = Some(D::erase(&self.object));
+    }
+
+    #[inline]
+    fn fill_dyn_mut<'a>(&'a mut self, slots: &mut [Option<&'a mut D>]) {
+        self.parent.fill_dyn_mut(slots);
+        slots[C::LEN] = Some(D::erase_mut(&mut self.object));
+    }
+}
+
+/// An object safe interface to inspect any chain of `'static` objects.
+///
+/// Unlike [`ChainElement`], this trait can be used as a trait object, e.g. `&dyn ErasedChain`, so
+/// code that can't be generic over the chain type can still access its objects. The objects can be
+/// downcast to their concrete types using [`Any`].
+///
+/// The method names differ from the ones of [`ChainElement`] and [`DynChain`], so that calling
+/// them on a concrete chain is not ambiguous.
+pub trait ErasedChain {
+    /// Returns the number of objects in the chain.
+    fn object_count(&self) -> usize;
+
+    /// Returns the object at `index`, or `None` if the index is out of bounds.
+    ///
+    /// Objects are indexed in the order they were appended.
+    fn get_any(&self, index: usize) -> Option<&dyn Any>;
+
+    /// Returns the object at `index` mutably, or `None` if the index is out of bounds.
+    ///
+    /// Objects are indexed in the order they were appended.
+    fn get_any_mut(&mut self, index: usize) -> Option<&mut dyn Any>;
+
+    /// Returns the object at `index`, or [`ChainError::OutOfBounds`] if the index is out of
+    /// bounds.
+    #[inline]
+    fn try_get_any(&self, index: usize) -> Result<&dyn Any, ChainError> {
+        let len = self.object_count();
+        self.get_any(index)
+            .ok_or(ChainError::OutOfBounds { index, len })
+    }
+
+    /// Returns the object at `index` mutably, or [`ChainError::OutOfBounds`] if the index is out
+    /// of bounds.
+    #[inline]
+    fn try_get_any_mut(&mut self, index: usize) -> Result<&mut dyn Any, ChainError> {
+        let len = self.object_count();
+        self.get_any_mut(index)
+            .ok_or(ChainError::OutOfBounds { index, len })
+    }
+}
+
+impl dyn ErasedChain + '_ {
+    /// Returns the object at `index` as a `T`.
+    ///
+    /// Returns [`ChainError::OutOfBounds`] if the index is out of bounds, or
+    /// [`ChainError::TypeMismatch`] if the object is not a `T`.
+    #[inline]
+    pub fn try_downcast_ref<T: Any>(&self, index: usize) -> Result<&T, ChainError> {
+        self.try_get_any(index)?
+            .downcast_ref()
+            .ok_or(ChainError::TypeMismatch { index })
+    }
+
+    /// Returns the object at `index` mutably as a `T`.
+    ///
+    /// Returns [`ChainError::OutOfBounds`] if the index is out of bounds, or
+    /// [`ChainError::TypeMismatch`] if the object is not a `T`.
+    #[inline]
+    pub fn try_downcast_mut<T: Any>(&mut self, index: usize) -> Result<&mut T, ChainError> {
+        self.try_get_any_mut(index)?
+            .downcast_mut()
+            .ok_or(ChainError::TypeMismatch { index })
+    }
+}
+
+impl<V: 'static> ErasedChain for Chain<V> {
+    #[inline]
+    fn object_count(&self) -> usize {
+        Self::LEN
+    }
+
+    #[inline]
+    fn get_any(&self, index: usize) -> Option<&dyn Any> {
+        if index == 0 {
+            Some(&self.object)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn get_any_mut(&mut self, index: usize) -> Option<&mut dyn Any> {
+        if index == 0 {
+            Some(&mut self.object)
+        } else {
+            None
+        }
+    }
+}
+
+impl<V: 'static, C: ChainElement + ErasedChain> ErasedChain for Link<V, C> {
+    #[inline]
+    fn object_count(&self) -> usize {
+        Self::LEN
+    }
+
+    #[inline]
+    fn get_any(&self, index: usize) -> Option<&dyn Any> {
+        if index == C::LEN {
+            Some(&self.object)
+        } else {
+            self.parent.get_any(index)
+        }
+    }
+
+    #[inline]
+    fn get_any_mut(&mut self, index: usize) -> Option<&mut dyn Any> {
+        if index == C::LEN {
+            Some(&mut self.object)
+        } else {
+            self.parent.get_any_mut(index)
+        }
+    }
+}
+
+/// A view of a chain that accesses every element as the trait object type `D`.
+///
+/// Use [`ChainElement::as_dyn`] to create a view.
+pub struct DynView<'a, C, D: ?Sized> {
+    chain: &'a C,
+    _marker: PhantomData<fn(&D)>,
+}
+
+impl<'a, C, D> DynView<'a, C, D>
+where
+    C: DynChain<D>,
+    D: ?Sized,
+{
+    #[inline]
+    pub(crate) fn new(chain: &'a C) -> Self {
+        Self {
+            chain,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Applies `f` to an accumulator and every element in the order they were appended, stopping
+    /// at the first error.
+    ///
+    /// This method works like [`Iterator::try_fold`].
+    #[inline]
+    pub fn try_fold<Acc, E, F>(&self, init: Acc, mut f: F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, &D) -> Result<Acc, E>,
+    {
+        self.chain.try_fold_dyn(init, &mut f)
+    }
+
+    /// Calls `f` on every element in the order they were appended, until `f` returns
+    /// [`ControlFlow::Break`].
+    ///
+    /// Returns the value `f` broke with, or [`ControlFlow::Continue`] if `f` visited every element.
+    #[inline]
+    pub fn for_each_until<B, F>(&self, mut f: F) -> ControlFlow<B>
+    where
+        F: FnMut(&D) -> ControlFlow<B>,
+    {
+        let result = self
+            .chain
+            .try_fold_dyn((), &mut |_, object| match f(object) {
+                ControlFlow::Continue(()) => Ok(()),
+                ControlFlow::Break(value) => Err(value),
+            });
+
+        match result {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(value) => ControlFlow::Break(value),
+        }
+    }
+
+    /// Applies `f` to every element and collects the results into an array, in the order the
+    /// elements were appended.
+    ///
+    /// The length of the array must be equal to the length of the chain, which is checked at
+    /// compile time.
+    #[inline]
+    pub fn map_to_array<U, F, const N: usize>(&self, mut f: F) -> [U; N]
+    where
+        F: FnMut(&D) -> U,
+    {
+        const {
+            assert!(N == C::LEN, "N must be equal to the length of the chain");
+        }
+
+        let mut slots = [const { None }; N];
+        let result: Result<usize, Infallible> = self.chain.try_fold_dyn(0, &mut |index, object| {
+            slots[index] = Some(f(object));
+            Ok(index + 1)
+        });
+        if let Err(never) = result {
+            match never {}
+        }
+
+        slots.map(|slot| match slot {
+            Some(value) => value,
+            None => unreachable!(),
+        })
+    }
+
+    /// Returns the number of elements for which `predicate` returns `true`.
+    #[inline]
+    pub fn count_where<F>(&self, mut predicate: F) -> usize
+    where
+        F: FnMut(&D) -> bool,
+    {
+        let result: Result<usize, Infallible> = self.chain.try_fold_dyn(0, &mut |count, object| {
+            Ok(count + predicate(object) as usize)
+        });
+
+        match result {
+            Ok(count) => count,
+            Err(never) => match never {},
+        }
+    }
+
+    /// Returns the index and a reference to the element with the minimum key, as extracted by `f`.
+    ///
+    /// If multiple elements have the minimum key, the first appended one is returned, like with
+    /// [`Iterator::min_by_key`].
+    #[inline]
+    pub fn min_by_key<K: Ord, F>(&self, mut f: F) -> (usize, &'a D)
+    where
+        F: FnMut(&D) -> K,
+    {
+        self.select_by_key(|key, best| key < best, &mut f)
+    }
+
+    /// Returns the index and a reference to the element with the maximum key, as extracted by `f`.
+    ///
+    /// If multiple elements have the maximum key, the last appended one is returned, like with
+    /// [`Iterator::max_by_key`].
+    #[inline]
+    pub fn max_by_key<K: Ord, F>(&self, mut f: F) -> (usize, &'a D)
+    where
+        F: FnMut(&D) -> K,
+    {
+        self.select_by_key(|key, best| key >= best, &mut f)
+    }
+
+    #[inline]
+    fn select_by_key<K, F>(&self, replaces: impl Fn(&K, &K) -> bool, f: &mut F) -> (usize, &'a D)
+    where
+        F: FnMut(&D) -> K,
+    {
+        let chain = self.chain;
+        let element = |index| match chain.get_dyn(index) {
+            Some(object) => object,
+            None => unreachable!(),
+        };
+
+        let mut best = (0, element(0));
+        let mut best_key = f(best.1);
+        for index in 1..C::LEN {
+            let object = element(index);
+            let key = f(object);
+            if replaces(&key, &best_key) {
+                best = (index, object);
+                best_key = key;
+            }
+        }
+        best
+    }
+
+    /// Returns the element whose type has the given [`KeyedElement::KEY`], or `None` if there is
+    /// no such element.
+    ///
+    /// If multiple elements have the same key, the last appended one is returned.
+    ///
+    /// [`KeyedElement::KEY`]: crate::KeyedElement::KEY
+    #[inline]
+    pub fn get_by_key(&self, key: &str) -> Option<&'a D>
+    where
+        C: KeyedChain,
+    {
+        let chain = self.chain;
+        C::position_of_key(key).and_then(|index| chain.get_dyn(index))
+    }
+
+    /// Returns the element whose type has the given [`KeyedElement::KEY`], or
+    /// [`ChainError::KeyNotFound`] if there is no such element.
+    ///
+    /// [`KeyedElement::KEY`]: crate::KeyedElement::KEY
+    #[inline]
+    pub fn try_get_by_key(&self, key: &str) -> Result<&'a D, ChainError>
+    where
+        C: KeyedChain,
+    {
+        self.get_by_key(key).ok_or(ChainError::KeyNotFound)
+    }
+
+    /// Returns an array of references to every element, in the order the elements were appended.
+    ///
+    /// The length of the array must be equal to the length of the chain, which is checked at
+    /// compile time.
+    #[inline]
+    pub fn into_array<const N: usize>(self) -> [&'a D; N] {
+        const {
+            assert!(N == C::LEN, "N must be equal to the length of the chain");
+        }
+
+        let mut slots = [const { None }; N];
+        self.chain.fill_dyn(&mut slots);
+        slots.map(|slot| match slot {
+            Some(object) => object,
+            None => unreachable!(),
+        })
+    }
+
+    /// Returns an iterator over the elements, in the order they were appended.
+    ///
+    /// The iterator is double-ended, so use [`Iterator::rev`] to visit the elements in the
+    /// reverse order, e.g. to tear them down.
+    #[inline]
+    pub fn iter(&self) -> DynIter<'a, C, D> {
+        DynIter {
+            chain: self.chain,
+            front: 0,
+            back: C::LEN,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, C, D> IntoIterator for DynView<'a, C, D>
+where
+    C: DynChain<D>,
+    D: ?Sized + 'a,
+{
+    type Item = &'a D;
+    type IntoIter = DynIter<'a, C, D>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the elements of a chain as the trait object type `D`.
+///
+/// Use [`DynView::iter`] to create an iterator.
+///
+/// Every call to [`next`](Iterator::next) and [`next_back`](DoubleEndedIterator::next_back) looks
+/// up the element by its index, which takes time proportional to the length of the chain, so
+/// visiting every element this way is quadratic in the length of the chain. For long chains, use
+/// [`DynView::into_array`], which collects every element in a single pass, and iterate over the
+/// array instead.
+pub struct DynIter<'a, C, D: ?Sized> {
+    chain: &'a C,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<fn(&D)>,
+}
+
+impl<'a, C, D> Iterator for DynIter<'a, C, D>
+where
+    C: DynChain<D>,
+    D: ?Sized + 'a,
+{
+    type Item = &'a D;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a D> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let object = self.chain.get_dyn(self.front);
+        self.front += 1;
+        object
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, C, D> DoubleEndedIterator for DynIter<'a, C, D>
+where
+    C: DynChain<D>,
+    D: ?Sized + 'a,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a D> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        self.chain.get_dyn(self.back)
+    }
+}
+
+impl<'a, C, D> ExactSizeIterator for DynIter<'a, C, D>
+where
+    C: DynChain<D>,
+    D: ?Sized + 'a,
+{
+}
+
+impl<'a, C, D> FusedIterator for DynIter<'a, C, D>
+where
+    C: DynChain<D>,
+    D: ?Sized + 'a,
+{
+}
+
+/// A view of a chain that mutably accesses every element as the trait object type `D`.
+///
+/// Use [`ChainElement::as_dyn_mut`] to create a view.
+pub struct DynViewMut<'a, C, D: ?Sized> {
+    chain: &'a mut C,
+    _marker: PhantomData<fn(&mut D)>,
+}
+
+impl<'a, C, D> DynViewMut<'a, C, D>
+where
+    C: DynChain<D>,
+    D: ?Sized,
+{
+    #[inline]
+    pub(crate) fn new(chain: &'a mut C) -> Self {
+        Self {
+            chain,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Applies `f` to an accumulator and every element in the order they were appended, stopping
+    /// at the first error.
+    ///
+    /// This method works like [`Iterator::try_fold`].
+    #[inline]
+    pub fn try_fold<Acc, E, F>(&mut self, init: Acc, mut f: F) -> Result<Acc, E>
+    where
+        F: FnMut(Acc, &mut D) -> Result<Acc, E>,
+    {
+        self.chain.try_fold_dyn_mut(init, &mut f)
+    }
+
+    /// Calls `f` on every element in the order they were appended, until `f` returns
+    /// [`ControlFlow::Break`].
+    ///
+    /// Returns the value `f` broke with, or [`ControlFlow::Continue`] if `f` visited every element.
+    #[inline]
+    pub fn for_each_until<B, F>(&mut self, mut f: F) -> ControlFlow<B>
+    where
+        F: FnMut(&mut D) -> ControlFlow<B>,
+    {
+        let result = self
+            .chain
+            .try_fold_dyn_mut((), &mut |_, object| match f(object) {
+                ControlFlow::Continue(()) => Ok(()),
+                ControlFlow::Break(value) => Err(value),
+            });
+
+        match result {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(value) => ControlFlow::Break(value),
+        }
+    }
+
+    /// Returns the element whose type has the given [`KeyedElement::KEY`] mutably, or `None` if
+    /// there is no such element.
+    ///
+    /// If multiple elements have the same key, the last appended one is returned.
+    ///
+    /// [`KeyedElement::KEY`]: crate::KeyedElement::KEY
+    #[inline]
+    pub fn get_by_key_mut(&mut self, key: &str) -> Option<&mut D>
+    where
+        C: KeyedChain,
+    {
+        match C::position_of_key(key) {
+            Some(index) => self.chain.get_dyn_mut(index),
+            None => None,
+        }
+    }
+
+    /// Returns the element whose type has the given [`KeyedElement::KEY`] mutably, or
+    /// [`ChainError::KeyNotFound`] if there is no such element.
+    ///
+    /// [`KeyedElement::KEY`]: crate::KeyedElement::KEY
+    #[inline]
+    pub fn try_get_by_key_mut(&mut self, key: &str) -> Result<&mut D, ChainError>
+    where
+        C: KeyedChain,
+    {
+        self.get_by_key_mut(key).ok_or(ChainError::KeyNotFound)
+    }
+
+    /// Returns an array of mutable references to every element, in the order the elements were
+    /// appended.
+    ///
+    /// This is useful to pass the objects of a chain to an API that expects a slice of trait
+    /// objects. The length of the array must be equal to the length of the chain, which is checked
+    /// at compile time.
+    #[inline]
+    pub fn into_array<const N: usize>(self) -> [&'a mut D; N] {
+        const {
+            assert!(N == C::LEN, "N must be equal to the length of the chain");
+        }
+
+        let mut slots = [const { None }; N];
+        self.chain.fill_dyn_mut(&mut slots);
+        slots.map(|slot| match slot {
+            Some(object) => object,
+            None => unreachable!(),
+        })
+    }
+}
+
+/// Chains whose every element can be moved into a box of the trait object type `D`.
+///
+/// This trait is implemented for every [`Chain`] and [`Link`] if `D` implements [`EraseBox`] for
+/// all the element types.
+#[cfg(feature = "alloc")]
+pub trait BoxedChain<D: ?Sized>: ChainElement {
+    /// Moves every element into a box and pushes them into `vec`, in the order they were appended.
+    fn push_boxed(self, vec: &mut Vec<Box<D>>);
+}
+
+#[cfg(feature = "alloc")]
+impl<D, V> BoxedChain<D> for Chain<V>
+where
+    D: ?Sized + EraseBox<V>,
+{
+    fn push_boxed(self, vec: &mut Vec<Box<D>>) {
+        vec.push(D::erase_box(Box::new(self.object)));
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<D, V, C> BoxedChain<D> for Link<V, C>
+where
+    D: ?Sized + EraseBox<V>,
+    C: BoxedChain<D>,
+{
+    fn push_boxed(self, vec: &mut Vec<Box<D>>) {
+        self.parent.push_boxed(vec);
+        vec.push(D::erase_box(Box::new(self.object)));
+    }
+}
+
+/// Implements [`Erase`] for a trait object type.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::impl_erase;
+///
+/// trait Widget {
+///     fn draw(&self);
+/// }
+///
+/// impl_erase!(dyn Widget);
+/// ```
+#[cfg(not(feature = "alloc"))]
+#[macro_export]
+macro_rules! impl_erase {
+    (dyn $bound:path) => {
+        impl<'a, T: $bound + 'a> $crate::Erase<T> for dyn $bound + 'a {
+            #[inline]
+            fn erase(item: &T) -> &Self {
+                item
+            }
+
+            #[inline]
+            fn erase_mut(item: &mut T) -> &mut Self {
+                item
+            }
+        }
+    };
+}
+
+/// Implements [`Erase`] and [`EraseBox`] for a trait object type.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::impl_erase;
+///
+/// trait Widget {
+///     fn draw(&self);
+/// }
+///
+/// impl_erase!(dyn Widget);
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! impl_erase {
+    (dyn $bound:path) => {
+        impl<'a, T: $bound + 'a> $crate::Erase<T> for dyn $bound + 'a {
+            #[inline]
+            fn erase(item: &T) -> &Self {
+                item
+            }
+
+            #[inline]
+            fn erase_mut(item: &mut T) -> &mut Self {
+                item
+            }
+        }
+
+        impl<'a, T: $bound + 'a> $crate::EraseBox<T> for dyn $bound + 'a {
+            #[inline]
+            fn erase_box(item: $crate::__private::Box<T>) -> $crate::__private::Box<Self> {
+                item
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement, ChainError, DynChain, ErasedChain};
+    #[cfg(feature = "alloc")]
+    use alloc::{boxed::Box, vec::Vec};
+    use core::ops::ControlFlow;
+
+    trait AsU8 {
+        fn as_u8(&self) -> u8;
+    }
+
+    impl AsU8 for u8 {
+        fn as_u8(&self) -> u8 {
+            *self
+        }
+    }
+
+    impl AsU8 for u16 {
+        fn as_u8(&self) -> u8 {
+            *self as u8
+        }
+    }
+
+    impl_erase!(dyn AsU8);
+
+    #[test]
+    pub fn test_try_fold() {
+        let chain = Chain::new(1u8).append(2u16).append(3u8);
+
+        let sum = chain
+            .as_dyn::<dyn AsU8>()
+            .try_fold(0, |acc, obj| Ok::<_, ()>(acc + obj.as_u8()));
+        assert_eq!(Ok(6), sum);
+
+        let result = chain.as_dyn::<dyn AsU8>().try_fold(0, |acc, obj| {
+            if obj.as_u8() == 2 {
+                Err(acc)
+            } else {
+                Ok(acc + obj.as_u8())
+            }
+        });
+        assert_eq!(Err(1), result);
+    }
+
+    #[test]
+    pub fn test_for_each_until() {
+        let mut chain = Chain::new(1u8).append(2u16).append(3u8);
+
+        let mut visited = 0;
+        let result = chain.as_dyn::<dyn AsU8>().for_each_until(|obj| {
+            visited += 1;
+            if obj.as_u8() == 2 {
+                ControlFlow::Break(obj.as_u8())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+        assert_eq!(ControlFlow::Break(2), result);
+        assert_eq!(2, visited);
+
+        let result = chain
+            .as_dyn_mut::<dyn AsU8>()
+            .for_each_until(|_| ControlFlow::<()>::Continue(()));
+        assert_eq!(ControlFlow::Continue(()), result);
+    }
+
+    #[test]
+    pub fn test_map_to_array() {
+        let chain = Chain::new(1u8).append(2u16).append(3u8);
+
+        let doubled: [u16; 3] = chain
+            .as_dyn::<dyn AsU8>()
+            .map_to_array(|obj| obj.as_u8() as u16 * 2);
+
+        assert_eq!([2, 4, 6], doubled);
+    }
+
+    #[test]
+    pub fn test_iter() {
+        let chain = Chain::new(1u8).append(2u16).append(3u8);
+
+        let mut order = [0; 3];
+        for (slot, object) in order
+            .iter_mut()
+            .zip(chain.as_dyn::<dyn AsU8>().iter().rev())
+        {
+            *slot = object.as_u8();
+        }
+        assert_eq!([3, 2, 1], order);
+
+        let mut iter = chain.as_dyn::<dyn AsU8>().into_iter();
+        assert_eq!(3, iter.len());
+        assert_eq!(Some(1), iter.next().map(|object| object.as_u8()));
+        assert_eq!(Some(3), iter.next_back().map(|object| object.as_u8()));
+        assert_eq!(Some(2), iter.next().map(|object| object.as_u8()));
+        assert!(iter.next_back().is_none());
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    pub fn test_count_where() {
+        let chain = Chain::new(3u8).append(1u16).append(4u8);
+
+        assert_eq!(
+            2,
+            chain
+                .as_dyn::<dyn AsU8>()
+                .count_where(|obj| obj.as_u8() > 2)
+        );
+        assert_eq!(
+            0,
+            chain
+                .as_dyn::<dyn AsU8>()
+                .count_where(|obj| obj.as_u8() > 4)
+        );
+    }
+
+    #[test]
+    pub fn test_min_max_by_key() {
+        let chain = Chain::new(3u8)
+            .append(1u16)
+            .append(4u8)
+            .append(1u8)
+            .append(4u16);
+        let view = chain.as_dyn::<dyn AsU8>();
+
+        let (index, min) = view.min_by_key(|obj| obj.as_u8());
+        assert_eq!((1, 1), (index, min.as_u8()));
+
+        let (index, max) = view.max_by_key(|obj| obj.as_u8());
+        assert_eq!((4, 4), (index, max.as_u8()));
+    }
+
+    #[test]
+    pub fn test_into_array() {
+        let mut chain = Chain::new(1u8).append(2u16).append(3u8);
+
+        let objects: [&dyn AsU8; 3] = chain.as_dyn().into_array();
+        assert_eq!(2, objects[1].as_u8());
+
+        let objects: [&mut dyn AsU8; 3] = chain.as_dyn_mut().into_array();
+        let sum: u8 = objects.iter().map(|object| object.as_u8()).sum();
+        assert_eq!(6, sum);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    pub fn test_into_boxed_iter() {
+        let chain = Chain::new(1u8).append(2u16).append(3u8);
+
+        let boxed: Vec<Box<dyn AsU8>> = chain.into_boxed_iter().collect();
+
+        assert_eq!(3, boxed.len());
+        assert_eq!(1, boxed[0].as_u8());
+        assert_eq!(2, boxed[1].as_u8());
+        assert_eq!(3, boxed[2].as_u8());
+    }
+
+    #[test]
+    pub fn test_erased_chain() {
+        fn inspect(chain: &mut dyn ErasedChain) -> Option<u16> {
+            *chain.get_any_mut(0)?.downcast_mut::<u8>()? += 1;
+            chain.get_any(1)?.downcast_ref::<u16>().copied()
+        }
+
+        let mut chain = Chain::new(1u8).append(2u16);
+
+        assert_eq!(Some(2), inspect(&mut chain));
+        assert_eq!(2, chain.object_count());
+        assert_eq!(
+            Some(&2u8),
+            chain.get_any(0).and_then(|obj| obj.downcast_ref())
+        );
+        assert!(chain.get_any(2).is_none());
+    }
+
+    #[test]
+    pub fn test_try_get() {
+        let mut chain = Chain::new(1u8).append(2u16);
+
+        assert_eq!(
+            2,
+            DynChain::<dyn AsU8>::try_get_dyn(&chain, 1)
+                .unwrap()
+                .as_u8()
+        );
+        assert_eq!(
+            Err(ChainError::OutOfBounds { index: 2, len: 2 }),
+            DynChain::<dyn AsU8>::try_get_dyn_mut(&mut chain, 2).map(|object| object.as_u8())
+        );
+
+        let erased: &mut dyn ErasedChain = &mut chain;
+        *erased.try_downcast_mut::<u16>(1).unwrap() += 1;
+        assert_eq!(Ok(&3u16), erased.try_downcast_ref(1));
+        assert_eq!(
+            Err(ChainError::TypeMismatch { index: 0 }),
+            erased.try_downcast_ref::<u16>(0)
+        );
+        assert_eq!(
+            Err(ChainError::OutOfBounds { index: 5, len: 2 }),
+            erased.try_downcast_ref::<u16>(5)
+        );
+    }
+}
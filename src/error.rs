@@ -0,0 +1,51 @@
+//! The error type of runtime chain accesses.
+
+use core::fmt::{self, Display, Formatter};
+
+/// The error returned when an object of a chain can't be accessed at runtime.
+///
+/// Accesses that are checked at compile time, like [`ChainElement::at`], can't fail. This error is
+/// returned by the `try_` variants of the accessors that take their arguments at runtime, e.g.
+/// [`DynChain::try_get_dyn`].
+///
+/// [`ChainElement::at`]: crate::ChainElement::at
+/// [`DynChain::try_get_dyn`]: crate::DynChain::try_get_dyn
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChainError {
+    /// The index is not less than the length of the chain.
+    OutOfBounds {
+        /// The requested index.
+        index: usize,
+
+        /// The length of the chain.
+        len: usize,
+    },
+
+    /// The object at the index doesn't have the requested type.
+    TypeMismatch {
+        /// The requested index.
+        index: usize,
+    },
+
+    /// The chain doesn't contain an object of the requested type.
+    TypeNotFound,
+
+    /// The chain doesn't contain an object with the requested key.
+    KeyNotFound,
+}
+
+impl Display for ChainError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds { index, len } => {
+                write!(f, "index {} is out of bounds for a chain of {}", index, len)
+            }
+            Self::TypeMismatch { index } => {
+                write!(f, "object {} doesn't have the requested type", index)
+            }
+            Self::TypeNotFound => f.write_str("no object has the requested type"),
+            Self::KeyNotFound => f.write_str("no object has the requested key"),
+        }
+    }
+}
@@ -0,0 +1,403 @@
+//! Selecting the objects of a chain by a type-level predicate.
+
+use crate::{private, Chain, ChainElement, Link};
+
+/// Marks a type as matching the predicate `P`, see [`Matches`].
+pub struct Keep;
+
+/// Marks a type as not matching the predicate `P`, see [`Matches`].
+pub struct Skip;
+
+/// Tells whether a type matches the predicate `P`.
+///
+/// `P` is usually a marker type named after a trait, e.g. `struct Calibratable;`. Every object type
+/// of a filtered chain must state its [`Decision`](Matches::Decision), either [`Keep`] or
+/// [`Skip`], for every predicate it is filtered by, see
+/// [type-level limitations](crate#type-level-limitations).
+///
+/// References implement this trait if the referenced type does, so chains of references, e.g.
+/// returned by [`ChainElement::as_mut_refs`], can be filtered as well.
+pub trait Matches<P> {
+    /// [`Keep`] if the type matches the predicate, [`Skip`] otherwise.
+    type Decision;
+}
+
+impl<P, T: Matches<P> + ?Sized> Matches<P> for &T {
+    type Decision = T::Decision;
+}
+
+impl<P, T: Matches<P> + ?Sized> Matches<P> for &mut T {
+    type Decision = T::Decision;
+}
+
+impl<V> private::Push<V> for () {
+    type Pushed = Chain<V>;
+
+    #[inline]
+    fn push(self, object: V) -> Chain<V> {
+        Chain::new(object)
+    }
+}
+
+impl<V, W> private::Push<V> for Chain<W> {
+    type Pushed = Link<V, Chain<W>>;
+
+    #[inline]
+    fn push(self, object: V) -> Self::Pushed {
+        self.append(object)
+    }
+}
+
+impl<V, W, C: ChainElement> private::Push<V> for Link<W, C> {
+    type Pushed = Link<V, Link<W, C>>;
+
+    #[inline]
+    fn push(self, object: V) -> Self::Pushed {
+        self.append(object)
+    }
+}
+
+impl<V, Acc: private::Push<V>> private::FilterStep<V, Acc> for Keep {
+    type Output = Acc::Pushed;
+
+    #[inline]
+    fn step(acc: Acc, object: V) -> Self::Output {
+        acc.push(object)
+    }
+}
+
+impl<V, Acc> private::FilterStep<V, Acc> for Skip {
+    type Output = Acc;
+
+    #[inline]
+    fn step(acc: Acc, _object: V) -> Acc {
+        acc
+    }
+}
+
+/// Implemented by chains whose objects all implement [`Matches<P>`].
+///
+/// The filtered chain contains the objects that match `P`, in the order they were appended. If no
+/// object matches, the filtered chain is `()`.
+///
+/// To filter without consuming the chain, filter a chain of references returned by
+/// [`ChainElement::as_refs`] or [`ChainElement::as_mut_refs`].
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, Keep, Matches, Skip};
+///
+/// trait Calibrate {
+///     fn calibrate(&mut self);
+/// }
+///
+/// struct Calibratable;
+///
+/// struct Thermometer(i16);
+/// struct Led(bool);
+/// struct Barometer(u32);
+///
+/// impl Matches<Calibratable> for Thermometer {
+///     type Decision = Keep;
+/// }
+///
+/// impl Matches<Calibratable> for Led {
+///     type Decision = Skip;
+/// }
+///
+/// impl Matches<Calibratable> for Barometer {
+///     type Decision = Keep;
+/// }
+///
+/// let mut devices = Chain::new(Thermometer(-5)).append(Led(false)).append(Barometer(1000));
+///
+/// let sensors = devices.as_mut_refs().filter::<Calibratable>();
+/// sensors.parent.object.0 = 0;
+/// sensors.object.0 = 1013;
+///
+/// assert_eq!(0, devices.parent.parent.object.0);
+/// assert_eq!(1013, devices.object.0);
+/// ```
+pub trait Filter<P> {
+    /// The chain of the objects that match `P`, or `()`.
+    type Filtered;
+
+    /// Returns the objects that match `P`, dropping the others.
+    fn filter_matching(self) -> Self::Filtered;
+}
+
+impl<P, V> Filter<P> for Chain<V>
+where
+    V: Matches<P>,
+    V::Decision: private::FilterStep<V, ()>,
+{
+    type Filtered = <V::Decision as private::FilterStep<V, ()>>::Output;
+
+    #[inline]
+    fn filter_matching(self) -> Self::Filtered {
+        <V::Decision as private::FilterStep<V, ()>>::step((), self.object)
+    }
+}
+
+impl<P, V, C> Filter<P> for Link<V, C>
+where
+    V: Matches<P>,
+    C: ChainElement + Filter<P>,
+    V::Decision: private::FilterStep<V, C::Filtered>,
+{
+    type Filtered = <V::Decision as private::FilterStep<V, C::Filtered>>::Output;
+
+    #[inline]
+    fn filter_matching(self) -> Self::Filtered {
+        let filtered = self.parent.filter_matching();
+        <V::Decision as private::FilterStep<V, C::Filtered>>::step(filtered, self.object)
+    }
+}
+
+impl<V, K: private::Push<V>, R> private::PartitionStep<V, K, R> for Keep {
+    type Kept = K::Pushed;
+    type Rest = R;
+
+    #[inline]
+    fn step(kept: K, rest: R, object: V) -> (Self::Kept, R) {
+        (kept.push(object), rest)
+    }
+}
+
+impl<V, K, R: private::Push<V>> private::PartitionStep<V, K, R> for Skip {
+    type Kept = K;
+    type Rest = R::Pushed;
+
+    #[inline]
+    fn step(kept: K, rest: R, object: V) -> (K, Self::Rest) {
+        (kept, rest.push(object))
+    }
+}
+
+/// Implemented by chains whose objects all implement [`Matches<P>`], to split them into two
+/// chains.
+///
+/// Both chains keep the order in which the objects were appended. Like with [`Filter`], a chain
+/// without objects is `()`.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{assert_chain_eq, Chain, ChainElement, Keep, Matches, Skip};
+///
+/// struct Realtime;
+///
+/// struct Motor(u8);
+/// struct Display(u8);
+///
+/// impl Matches<Realtime> for Motor {
+///     type Decision = Keep;
+/// }
+///
+/// impl Matches<Realtime> for Display {
+///     type Decision = Skip;
+/// }
+///
+/// let devices = Chain::new(Motor(1)).append(Display(2)).append(Motor(3));
+///
+/// let (realtime, background) = devices.partition::<Realtime>();
+///
+/// assert_eq!(1, realtime.parent.object.0);
+/// assert_eq!(3, realtime.object.0);
+/// assert_eq!(2, background.object.0);
+/// ```
+pub trait Partition<P> {
+    /// The chain of the objects that match `P`, or `()`.
+    type Kept;
+
+    /// The chain of the objects that don't match `P`, or `()`.
+    type Rest;
+
+    /// Splits the chain into the objects that match `P` and the ones that don't.
+    fn partition_matching(self) -> (Self::Kept, Self::Rest);
+}
+
+impl<P, V> Partition<P> for Chain<V>
+where
+    V: Matches<P>,
+    V::Decision: private::PartitionStep<V, (), ()>,
+{
+    type Kept = <V::Decision as private::PartitionStep<V, (), ()>>::Kept;
+    type Rest = <V::Decision as private::PartitionStep<V, (), ()>>::Rest;
+
+    #[inline]
+    fn partition_matching(self) -> (Self::Kept, Self::Rest) {
+        <V::Decision as private::PartitionStep<V, (), ()>>::step((), (), self.object)
+    }
+}
+
+impl<P, V, C> Partition<P> for Link<V, C>
+where
+    V: Matches<P>,
+    C: ChainElement + Partition<P>,
+    V::Decision: private::PartitionStep<V, C::Kept, C::Rest>,
+{
+    type Kept = <V::Decision as private::PartitionStep<V, C::Kept, C::Rest>>::Kept;
+    type Rest = <V::Decision as private::PartitionStep<V, C::Kept, C::Rest>>::Rest;
+
+    #[inline]
+    fn partition_matching(self) -> (Self::Kept, Self::Rest) {
+        let (kept, rest) = self.parent.partition_matching();
+        <V::Decision as private::PartitionStep<V, C::Kept, C::Rest>>::step(kept, rest, self.object)
+    }
+}
+
+impl private::CountStep for Keep {
+    const COUNT: usize = 1;
+}
+
+impl private::CountStep for Skip {
+    const COUNT: usize = 0;
+}
+
+/// Implemented by chains whose objects all implement [`Matches<P>`], to count the objects that
+/// match `P` at compile time. Use [`count_of`] to read the count.
+pub trait CountMatching<P>: ChainElement {
+    /// The number of objects that match `P`.
+    const COUNT: usize;
+}
+
+impl<P, V> CountMatching<P> for Chain<V>
+where
+    V: Matches<P>,
+    V::Decision: private::CountStep,
+{
+    const COUNT: usize = <V::Decision as private::CountStep>::COUNT;
+}
+
+impl<P, V, C> CountMatching<P> for Link<V, C>
+where
+    V: Matches<P>,
+    C: CountMatching<P>,
+    V::Decision: private::CountStep,
+{
+    const COUNT: usize = C::COUNT + <V::Decision as private::CountStep>::COUNT;
+}
+
+/// Returns the number of objects in the chain `C` that match `P`.
+///
+/// The count is evaluated at compile time. To count the objects of a type, use the type itself as
+/// the predicate, and let every object type state whether it is counted. This only works for types
+/// defined in your crate, see [type-level limitations](crate#type-level-limitations). Use
+/// [`ChainElement::count_of`] to count objects of foreign types at runtime.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{chain, count_of, Chain, Keep, Link, Matches, Skip};
+///
+/// struct Defaults;
+/// struct Override;
+/// struct Logger;
+///
+/// impl Matches<Override> for Defaults {
+///     type Decision = Skip;
+/// }
+///
+/// impl Matches<Override> for Override {
+///     type Decision = Keep;
+/// }
+///
+/// impl Matches<Override> for Logger {
+///     type Decision = Skip;
+/// }
+///
+/// type Layers = chain![Defaults, Override, Logger, Override];
+///
+/// const OVERRIDES: usize = count_of::<Layers, Override>();
+///
+/// assert_eq!(2, OVERRIDES);
+/// ```
+#[inline]
+pub const fn count_of<C: CountMatching<P>, P>() -> usize {
+    C::COUNT
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{assert_chain_eq, count_of, Chain, ChainElement, Keep, Link, Matches, Skip};
+
+    struct Even;
+
+    impl Matches<Even> for u8 {
+        type Decision = Keep;
+    }
+
+    impl Matches<Even> for u16 {
+        type Decision = Skip;
+    }
+
+    impl Matches<Even> for u32 {
+        type Decision = Keep;
+    }
+
+    #[test]
+    pub fn test_filter() {
+        let chain = Chain::new(1u8).append(2u16).append(3u32).append(4u16);
+
+        assert_chain_eq!(chain.filter::<Even>(), (1, 3));
+    }
+
+    #[test]
+    pub fn test_filter_empty() {
+        let () = Chain::new(1u16).append(2u16).filter::<Even>();
+    }
+
+    #[test]
+    pub fn test_partition() {
+        let chain = Chain::new(1u8).append(2u16).append(3u32).append(4u16);
+
+        let (even, odd) = chain.partition::<Even>();
+
+        assert_chain_eq!(even, (1, 3));
+        assert_chain_eq!(odd, (2, 4));
+
+        let ((), odd) = Chain::new(5u16).partition::<Even>();
+        assert_chain_eq!(odd, (5,));
+    }
+
+    #[test]
+    pub fn test_filter_refs() {
+        let mut chain = Chain::new(1u16).append(2u8).append(3u16);
+
+        let filtered = chain.as_mut_refs().filter::<Even>();
+        *filtered.object += 5;
+
+        assert_chain_eq!(chain.as_refs().filter::<Even>(), (&7,));
+    }
+
+    #[test]
+    pub fn test_count_of() {
+        type Objects = Link<u8, Link<u32, Link<u16, Chain<u8>>>>;
+
+        const COUNT: usize = count_of::<Objects, Even>();
+
+        assert_eq!(3, COUNT);
+        assert_eq!(0, count_of::<Chain<u16>, Even>());
+    }
+
+    #[test]
+    pub fn test_count_of_type() {
+        struct Sensor;
+
+        impl Matches<Sensor> for Sensor {
+            type Decision = Keep;
+        }
+
+        impl Matches<Sensor> for u16 {
+            type Decision = Skip;
+        }
+
+        assert_eq!(
+            2,
+            count_of::<Link<Sensor, Link<u16, Chain<Sensor>>>, Sensor>()
+        );
+        assert_eq!(0, count_of::<Chain<u16>, Sensor>());
+    }
+}
@@ -0,0 +1,147 @@
+//! Chains where every object has the same type.
+
+use core::{mem::ManuallyDrop, ptr, slice};
+
+use crate::{chain, private, Chain, ChainElement, Link};
+
+/// A chain whose every object is of type `T`.
+///
+/// [`Chain`] and [`Link`] are `#[repr(C)]`, which means the objects of a homogeneous chain are laid
+/// out in memory exactly like an array, in the order they were appended. This trait is sealed and
+/// implemented for every chain that has this property.
+///
+/// [`Chain`]: crate::Chain
+/// [`Link`]: crate::Link
+pub trait Homogeneous<T>: ChainElement + private::SealedHomogeneous<T> + Sized {
+    /// Returns the objects of the chain as a slice.
+    #[inline]
+    fn as_slice(&self) -> &[T] {
+        // Safety: the chain has the same layout as `[T; self.len()]`, see the trait documentation.
+        unsafe { slice::from_raw_parts(self as *const Self as *const T, self.len()) }
+    }
+
+    /// Returns the objects of the chain as a mutable slice.
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        // Safety: the chain has the same layout as `[T; self.len()]`, see the trait documentation.
+        unsafe { slice::from_raw_parts_mut(self as *mut Self as *mut T, self.len()) }
+    }
+
+    /// Returns an iterator over the objects of the chain, in the order they were appended.
+    #[inline]
+    fn iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Returns an iterator that allows modifying the objects of the chain, in the order they were
+    /// appended.
+    #[inline]
+    fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Converts the chain into an array of its objects, in the order they were appended.
+    ///
+    /// This method is available for chains of up to 32 objects.
+    #[inline]
+    fn into_array<const N: usize>(self) -> [T; N]
+    where
+        Self: Into<[T; N]>,
+    {
+        self.into()
+    }
+}
+
+impl<T> Homogeneous<T> for Chain<T> {}
+impl<T, C: Homogeneous<T>> Homogeneous<T> for Link<T, C> {}
+
+macro_rules! impl_array_conversions {
+    ($($n:tt)+) => {
+        $(
+            impl<T> From<[T; $n]> for chain![T; $n] {
+                #[inline]
+                fn from(array: [T; $n]) -> Self {
+                    let array = ManuallyDrop::new(array);
+
+                    // Safety: the chain has the same layout as the array, see `Homogeneous`.
+                    unsafe { ptr::read(&*array as *const [T; $n] as *const Self) }
+                }
+            }
+
+            impl<T> From<chain![T; $n]> for [T; $n] {
+                #[inline]
+                fn from(chain: chain![T; $n]) -> Self {
+                    let chain = ManuallyDrop::new(chain);
+
+                    // Safety: the chain has the same layout as the array, see `Homogeneous`.
+                    unsafe { ptr::read(&*chain as *const chain![T; $n] as *const Self) }
+                }
+            }
+        )+
+    };
+}
+
+impl_array_conversions!(1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32);
+
+#[cfg(test)]
+mod test {
+    use crate::{chain, Chain, ChainElement, Homogeneous, Link};
+    use core::cell::Cell;
+
+    #[test]
+    pub fn test_as_slice() {
+        let mut chain: chain![u16; 4] = Chain::new(4).append(1).append(3).append(2);
+
+        assert_eq!(&[4, 1, 3, 2], chain.as_slice());
+
+        chain.as_mut_slice().sort_unstable();
+        assert_eq!(&[1, 2, 3, 4], chain.as_slice());
+        assert_eq!(4, *chain.get());
+    }
+
+    #[test]
+    pub fn test_as_slice_of_zero_sized_types() {
+        let chain: chain![(); 3] = Chain::new(()).append(()).append(());
+
+        assert_eq!(3, chain.as_slice().len());
+    }
+
+    #[test]
+    pub fn test_array_conversions() {
+        let chain: chain![u8; 3] = [1, 2, 3].into();
+        assert_eq!(&[1, 2, 3], chain.as_slice());
+
+        let (obj, chain) = chain.pop();
+        assert_eq!(3, obj);
+        assert_eq!([1, 2], chain.into_array());
+    }
+
+    #[test]
+    pub fn test_array_conversion_drops_objects_once() {
+        struct Counted<'a>(&'a Cell<u32>);
+
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+
+        let chain = <chain![Counted; 2]>::from([Counted(&drops), Counted(&drops)]);
+        let array = chain.into_array();
+        assert_eq!(0, drops.get());
+
+        drop(array);
+        assert_eq!(2, drops.get());
+    }
+
+    #[test]
+    pub fn test_iter() {
+        let mut chain = Chain::new(1u32).append(2).append(3);
+
+        chain.iter_mut().for_each(|obj| *obj *= 2);
+        assert_eq!(12, chain.iter().sum::<u32>());
+        assert_eq!(Some(&6), chain.iter().next_back());
+    }
+}
@@ -0,0 +1,56 @@
+//! Generating chains from unstructured data using `arbitrary`.
+//!
+//! The objects are generated in the order they were appended, so a fuzzer mutating the start of
+//! the input affects the first objects of the chain.
+
+use ::arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+use crate::{Chain, ChainElement, Link};
+
+impl<'a, V: Arbitrary<'a>> Arbitrary<'a> for Chain<V> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        V::arbitrary(u).map(Chain::new)
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        V::size_hint(depth)
+    }
+}
+
+impl<'a, V: Arbitrary<'a>, C: ChainElement + Arbitrary<'a>> Arbitrary<'a> for Link<V, C> {
+    #[inline]
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let parent = C::arbitrary(u)?;
+        let object = V::arbitrary(u)?;
+
+        Ok(Link { parent, object })
+    }
+
+    #[inline]
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and(C::size_hint(depth), V::size_hint(depth))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, Link};
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    pub fn test_arbitrary() {
+        let mut data = Unstructured::new(&[1, 2, 3, 1]);
+
+        let chain = <Link<bool, Link<u16, Chain<u8>>>>::arbitrary(&mut data).unwrap();
+
+        assert_eq!(1, chain.parent.parent.object);
+        assert_eq!(u16::from_le_bytes([2, 3]), chain.parent.object);
+        assert!(chain.object);
+        assert_eq!(
+            (4, Some(4)),
+            <Link<bool, Link<u16, Chain<u8>>>>::size_hint(0)
+        );
+    }
+}
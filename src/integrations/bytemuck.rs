@@ -0,0 +1,57 @@
+//! Casting chains to bytes using `bytemuck`.
+//!
+//! Any chain of [`Zeroable`] objects is itself [`Zeroable`]. [`Pod`] is only implemented for
+//! homogeneous chains: objects of different types may be separated by padding bytes, which `Pod`
+//! does not allow. A chain of `Pod` objects of the same type has the layout of an array and can be
+//! cast to `&[u8]` with [`bytemuck::bytes_of`].
+
+use ::bytemuck::{Pod, Zeroable};
+
+use crate::{Chain, ChainElement, Homogeneous, Link};
+
+// Safety: `Chain` is `repr(C)` and only contains a `V`, so the all-zero pattern is valid.
+unsafe impl<V: Zeroable> Zeroable for Chain<V> {}
+
+// Safety: `Link` is `repr(C)` and only contains a `V` and a `C`, both of which are valid when
+// zeroed. Padding bytes do not need to be valid.
+unsafe impl<V: Zeroable, C: ChainElement + Zeroable> Zeroable for Link<V, C> {}
+
+// Safety: `Chain` is `repr(C)` with a single `Pod` field, so it has no padding.
+unsafe impl<V: Pod> Pod for Chain<V> {}
+
+// Safety: every object in a homogeneous chain has the same size and alignment, so no padding is
+// inserted between the parent and the object. The parent is `Pod` so it has no padding either.
+unsafe impl<V: Pod, C: Homogeneous<V> + Pod> Pod for Link<V, C> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{chain, Chain, ChainElement, Link};
+    use ::bytemuck::Zeroable;
+
+    #[test]
+    pub fn test_zeroed() {
+        let chain = <Link<u32, Chain<u8>>>::zeroed();
+
+        assert_eq!(0, chain.parent.object);
+        assert_eq!(0, chain.object);
+    }
+
+    #[test]
+    pub fn test_bytes_of() {
+        let chain = Chain::new(0x0102u16).append(0x0304).append(0x0506);
+
+        let bytes = ::bytemuck::bytes_of(&chain);
+
+        assert_eq!(6, bytes.len());
+        assert_eq!(0x0102u16.to_ne_bytes(), bytes[0..2]);
+        assert_eq!(0x0506u16.to_ne_bytes(), bytes[4..6]);
+    }
+
+    #[test]
+    pub fn test_cast_from_bytes() {
+        let chain: chain![[u8; 2]; 3] = ::bytemuck::cast([1u8, 2, 3, 4, 5, 6]);
+
+        assert_eq!([1, 2], chain.parent.parent.object);
+        assert_eq!([5, 6], chain.object);
+    }
+}
@@ -0,0 +1,176 @@
+//! Sharing chains with interrupt handlers using `critical-section`.
+
+use core::cell::RefCell;
+
+use ::critical_section::{CriticalSection, Mutex};
+
+use crate::{ChainElement, DynChain, DynViewMut, Selector, Wrapper};
+
+/// Wraps every object into a [`Mutex`] of a [`RefCell`], so that the objects of a chain can be
+/// shared separately. Use [`ChainElement::lock_of`] to access the objects.
+pub struct InMutex;
+
+impl Wrapper for InMutex {
+    type Wrapped<T> = Mutex<RefCell<T>>;
+
+    #[inline]
+    fn wrap<T>(object: T) -> Self::Wrapped<T> {
+        Mutex::new(RefCell::new(object))
+    }
+}
+
+/// A chain that can be shared between the main program and interrupt handlers.
+///
+/// The chain is stored in a [`Mutex`], and every access happens inside a critical section. Because
+/// [`SharedChain::new`] is a `const fn`, a `SharedChain` can be stored in a `static`, e.g. using
+/// [`static_chain!`].
+///
+/// [`static_chain!`]: crate::static_chain
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{static_chain, ChainElement, SharedChain};
+///
+/// struct Counter(u32);
+/// struct Flag(bool);
+///
+/// static_chain! {
+///     static DEVICES: SharedChain<[Counter, Flag]> = [Counter(0), Flag(false)];
+/// }
+///
+/// // In an interrupt handler:
+/// DEVICES.with_of::<Counter, _, _>(|counter| counter.0 += 1);
+///
+/// assert_eq!(1, DEVICES.with(|chain| chain.get_of::<Counter, _>().0));
+/// ```
+pub struct SharedChain<C> {
+    chain: Mutex<RefCell<C>>,
+}
+
+impl<C: ChainElement> SharedChain<C> {
+    /// Wraps the given chain.
+    #[inline]
+    pub const fn new(chain: C) -> Self {
+        Self {
+            chain: Mutex::new(RefCell::new(chain)),
+        }
+    }
+
+    /// Calls `f` with a mutable reference to the chain, inside a critical section.
+    #[inline]
+    pub fn with<R>(&self, f: impl FnOnce(&mut C) -> R) -> R {
+        ::critical_section::with(|cs| self.with_cs(cs, f))
+    }
+
+    /// Calls `f` with a mutable reference to the chain, inside an already acquired critical
+    /// section.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the chain is already borrowed, i.e. if this is called from inside `f`.
+    #[inline]
+    pub fn with_cs<R>(&self, cs: CriticalSection<'_>, f: impl FnOnce(&mut C) -> R) -> R {
+        f(&mut self.chain.borrow_ref_mut(cs))
+    }
+
+    /// Calls `f` with a mutable reference to the object of type `T`, inside a critical section.
+    ///
+    /// The index type `I` is inferred by the compiler if the chain contains a single `T` object.
+    #[inline]
+    pub fn with_of<T, I, R>(&self, f: impl FnOnce(&mut T) -> R) -> R
+    where
+        C: Selector<T, I>,
+    {
+        self.with(|chain| f(chain.select_mut()))
+    }
+
+    /// Calls `f` with a view that accesses every object as the trait object type `D`, inside a
+    /// critical section.
+    #[inline]
+    pub fn with_dyn<D, R>(&self, f: impl FnOnce(DynViewMut<'_, C, D>) -> R) -> R
+    where
+        C: DynChain<D>,
+        D: ?Sized,
+    {
+        self.with(|chain| f(chain.as_dyn_mut()))
+    }
+
+    /// Consumes the wrapper and returns the chain.
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.chain.into_inner().into_inner()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::ops::ControlFlow;
+
+    use crate::{impl_erase, Chain, ChainElement, InMutex, SharedChain};
+
+    trait Tick {
+        fn tick(&mut self);
+
+        fn ticks(&self) -> u32;
+    }
+
+    impl_erase!(dyn Tick);
+
+    struct Timer(u32);
+    struct Watchdog(u32);
+
+    impl Tick for Timer {
+        fn tick(&mut self) {
+            self.0 += 1;
+        }
+
+        fn ticks(&self) -> u32 {
+            self.0
+        }
+    }
+
+    impl Tick for Watchdog {
+        fn tick(&mut self) {
+            self.0 += 2;
+        }
+
+        fn ticks(&self) -> u32 {
+            self.0
+        }
+    }
+
+    #[test]
+    pub fn test_shared_chain() {
+        let shared = SharedChain::new(Chain::new(Timer(0)).append(Watchdog(0)));
+
+        shared.with_of::<Timer, _, _>(|timer| timer.tick());
+        let visited = shared.with_dyn::<dyn Tick, _>(|mut view| {
+            view.for_each_until(|elem| {
+                elem.tick();
+                ControlFlow::<()>::Continue(())
+            })
+        });
+
+        assert_eq!(ControlFlow::Continue(()), visited);
+
+        let chain = shared.into_inner();
+        assert_eq!(2, chain.parent.object.0);
+        assert_eq!(2, chain.get_of::<Watchdog, _>().ticks());
+    }
+
+    #[test]
+    pub fn test_wrap_in_mutex() {
+        let chain = Chain::new(Timer(0))
+            .append(Watchdog(0))
+            .wrap_each::<InMutex>();
+
+        ::critical_section::with(|cs| {
+            chain.lock_of::<Timer, _>(cs).tick();
+            chain.lock_of::<Watchdog, _>(cs).tick();
+
+            assert_eq!(1, chain.lock_of::<Timer, _>(cs).ticks());
+            assert_eq!(2, chain.lock_of::<Watchdog, _>(cs).ticks());
+        });
+    }
+}
@@ -0,0 +1,123 @@
+//! Spawning chains of `embassy-executor` tasks.
+
+use ::embassy_executor::Spawner;
+
+use crate::{Chain, ChainElement, Link};
+
+/// An object that spawns one or more tasks on an `embassy-executor` executor.
+///
+/// Tasks declared with `#[embassy_executor::task]` can't be stored in a chain directly, because
+/// calling them creates a spawn token that borrows the static task storage. Implement this trait
+/// for a struct of the task arguments instead, and call the task function in
+/// [`SpawnInto::spawn_into`].
+///
+/// # Example:
+///
+/// ```rust,ignore
+/// struct Blinky {
+///     led: Output<'static>,
+/// }
+///
+/// #[embassy_executor::task]
+/// async fn blinky(led: Output<'static>) {
+///     // ...
+/// }
+///
+/// impl SpawnInto for Blinky {
+///     type Output = Result<(), SpawnError>;
+///
+///     fn spawn_into(self, spawner: Spawner) -> Self::Output {
+///         spawner.spawn(blinky(self.led)?);
+///         Ok(())
+///     }
+/// }
+///
+/// let results = Chain::new(Blinky { led }).append(Logger { uart }).spawn_all(spawner);
+/// ```
+pub trait SpawnInto {
+    /// The result of spawning, e.g. `Result<(), SpawnError>` or a handle to the spawned task.
+    type Output;
+
+    /// Spawns the task on `spawner`.
+    fn spawn_into(self, spawner: Spawner) -> Self::Output;
+}
+
+/// A chain of [`SpawnInto`] objects.
+pub trait SpawnChain: ChainElement {
+    /// A chain of the spawn results, in the same order as the objects.
+    type Outputs: ChainElement;
+
+    /// Spawns every object on `spawner`, in the order they were appended, and returns a chain of
+    /// the results.
+    ///
+    /// Spawning continues after a failed spawn, so every result can be inspected.
+    fn spawn_all(self, spawner: Spawner) -> Self::Outputs;
+}
+
+impl<T: SpawnInto> SpawnChain for Chain<T> {
+    type Outputs = Chain<T::Output>;
+
+    #[inline]
+    fn spawn_all(self, spawner: Spawner) -> Self::Outputs {
+        Chain::new(self.object.spawn_into(spawner))
+    }
+}
+
+impl<T: SpawnInto, C: SpawnChain> SpawnChain for Link<T, C> {
+    type Outputs = Link<T::Output, C::Outputs>;
+
+    #[inline]
+    fn spawn_all(self, spawner: Spawner) -> Self::Outputs {
+        let parent = self.parent.spawn_all(spawner);
+        parent.append(self.object.spawn_into(spawner))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+
+    use core::sync::atomic::{AtomicU32, Ordering};
+    use std::boxed::Box;
+
+    use ::embassy_executor::{raw::Executor, SpawnError, Spawner};
+
+    use super::*;
+
+    #[unsafe(export_name = "__pender")]
+    fn pender(_context: *mut ()) {}
+
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    #[::embassy_executor::task]
+    async fn count(amount: u32) {
+        COUNTER.fetch_add(amount, Ordering::Relaxed);
+    }
+
+    struct Count(u32);
+
+    impl SpawnInto for Count {
+        type Output = Result<(), SpawnError>;
+
+        fn spawn_into(self, spawner: Spawner) -> Self::Output {
+            spawner.spawn(count(self.0)?);
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn test_spawn_all() {
+        let executor: &'static Executor = Box::leak(Box::new(Executor::new(core::ptr::null_mut())));
+
+        let results = Chain::new(Count(1))
+            .append(Count(2))
+            .spawn_all(executor.spawner());
+
+        // The task pool has a single slot, which is taken by the first task.
+        assert!(results.parent.object.is_ok());
+        assert!(matches!(results.object, Err(SpawnError::Busy)));
+
+        unsafe { executor.poll() };
+        assert_eq!(1, COUNTER.load(Ordering::Relaxed));
+    }
+}
@@ -0,0 +1,121 @@
+//! Scatter-gather DMA transfers using `embedded-dma` buffers.
+//!
+//! The buffers of a chain are not contiguous in memory, so a chain can't implement
+//! [`ReadBuffer`] or [`WriteBuffer`] itself. Instead, chains of buffers can produce a list of
+//! descriptors, one for every buffer, which can be handed to a scatter-gather capable DMA driver.
+
+use ::embedded_dma::{ReadBuffer, WriteBuffer};
+
+use crate::{Chain, ChainElement, Link};
+
+/// Implemented by chains of [`ReadBuffer`]s with the same word type `W`.
+pub trait ReadBufferChain<W>: ChainElement {
+    /// Returns the pointer and length of every buffer, in the order they were appended.
+    ///
+    /// `N` must be equal to the length of the chain, which is checked at compile time.
+    ///
+    /// # Safety
+    ///
+    /// The same rules apply as for [`ReadBuffer::read_buffer`]: once this method has been called,
+    /// it is unsafe to call any `&mut self` methods on the chain as long as the returned
+    /// descriptors are in use by DMA.
+    #[inline]
+    unsafe fn read_descriptors<const N: usize>(&self) -> [(*const W, usize); N] {
+        const { assert!(N == Self::LEN, "N must be equal to the length of the chain") };
+
+        let mut descriptors = [(core::ptr::null(), 0); N];
+        self.fill_read_descriptors(&mut descriptors);
+        descriptors
+    }
+
+    #[doc(hidden)]
+    unsafe fn fill_read_descriptors(&self, descriptors: &mut [(*const W, usize)]);
+}
+
+/// Implemented by chains of [`WriteBuffer`]s with the same word type `W`.
+pub trait WriteBufferChain<W>: ChainElement {
+    /// Returns the pointer and length of every buffer, in the order they were appended.
+    ///
+    /// `N` must be equal to the length of the chain, which is checked at compile time.
+    ///
+    /// # Safety
+    ///
+    /// The same rules apply as for [`WriteBuffer::write_buffer`]: once this method has been
+    /// called, it is unsafe to call any `&mut self` methods, except for this one, on the chain as
+    /// long as the returned descriptors are in use by DMA.
+    #[inline]
+    unsafe fn write_descriptors<const N: usize>(&mut self) -> [(*mut W, usize); N] {
+        const { assert!(N == Self::LEN, "N must be equal to the length of the chain") };
+
+        let mut descriptors = [(core::ptr::null_mut(), 0); N];
+        self.fill_write_descriptors(&mut descriptors);
+        descriptors
+    }
+
+    #[doc(hidden)]
+    unsafe fn fill_write_descriptors(&mut self, descriptors: &mut [(*mut W, usize)]);
+}
+
+impl<W, B: ReadBuffer<Word = W>> ReadBufferChain<W> for Chain<B> {
+    #[inline]
+    unsafe fn fill_read_descriptors(&self, descriptors: &mut [(*const W, usize)]) {
+        descriptors[0] = self.object.read_buffer();
+    }
+}
+
+impl<W, B: ReadBuffer<Word = W>, C: ReadBufferChain<W>> ReadBufferChain<W> for Link<B, C> {
+    #[inline]
+    unsafe fn fill_read_descriptors(&self, descriptors: &mut [(*const W, usize)]) {
+        self.parent.fill_read_descriptors(descriptors);
+        descriptors[C::LEN] = self.object.read_buffer();
+    }
+}
+
+impl<W, B: WriteBuffer<Word = W>> WriteBufferChain<W> for Chain<B> {
+    #[inline]
+    unsafe fn fill_write_descriptors(&mut self, descriptors: &mut [(*mut W, usize)]) {
+        descriptors[0] = self.object.write_buffer();
+    }
+}
+
+impl<W, B: WriteBuffer<Word = W>, C: WriteBufferChain<W>> WriteBufferChain<W> for Link<B, C> {
+    #[inline]
+    unsafe fn fill_write_descriptors(&mut self, descriptors: &mut [(*mut W, usize)]) {
+        self.parent.fill_write_descriptors(descriptors);
+        descriptors[C::LEN] = self.object.write_buffer();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement, ReadBufferChain, WriteBufferChain};
+
+    #[test]
+    pub fn test_read_descriptors() {
+        let header: &'static [u8; 2] = &[1, 2];
+        let payload: &'static [u8] = &[3, 4, 5];
+        let chain = Chain::new(header).append(payload);
+
+        let descriptors = unsafe { chain.read_descriptors::<2>() };
+
+        assert_eq!((header.as_ptr(), 2), descriptors[0]);
+        assert_eq!((payload.as_ptr(), 3), descriptors[1]);
+    }
+
+    #[test]
+    pub fn test_write_descriptors() {
+        extern crate std;
+        use std::boxed::Box;
+
+        let header: &'static mut [u8; 2] = Box::leak(Box::new([0; 2]));
+        let payload: &'static mut [u8] = Box::leak(Box::new([0; 4]));
+        let header_ptr = header.as_mut_ptr();
+        let payload_ptr = payload.as_mut_ptr();
+        let mut chain = Chain::new(header).append(payload);
+
+        let descriptors = unsafe { chain.write_descriptors::<2>() };
+
+        assert_eq!((header_ptr, 2), descriptors[0]);
+        assert_eq!((payload_ptr, 4), descriptors[1]);
+    }
+}
@@ -0,0 +1,334 @@
+//! Driving a chain of `embedded-hal` output pins together.
+//!
+//! A chain of [`OutputPin`] objects is itself an [`OutputPin`], which drives every pin to the same
+//! level, in the order they were appended. This can be used to control multiple status LEDs or
+//! enable lines as one pin, even if they are driven by different GPIO drivers.
+//!
+//! If every pin is a [`StatefulOutputPin`], the chain is set high or low if every pin in it is set
+//! high or low, respectively. Toggling the chain toggles every pin individually.
+//!
+//! If a pin fails, the remaining pins are still driven, and the error of the first failing pin is
+//! returned, as a [`PinError`].
+//!
+//! A chain of pins can also be used as a parallel bus, driving each pin to a different level, using
+//! [`ParallelBus`].
+
+use core::fmt::{self, Display, Formatter};
+
+use ::embedded_hal::digital::{Error, ErrorKind, ErrorType, OutputPin, StatefulOutputPin};
+
+use crate::{private, Chain, ChainElement, ExactLength, Link};
+
+/// The error returned by a chain of pins.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PinError {
+    /// The position of the first pin that failed, in the order they were appended.
+    pub index: usize,
+
+    /// The kind of the error that the pin returned.
+    pub kind: ErrorKind,
+}
+
+impl PinError {
+    #[inline]
+    fn new(index: usize, error: impl Error) -> Self {
+        Self {
+            index,
+            kind: error.kind(),
+        }
+    }
+}
+
+impl Error for PinError {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl Display for PinError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "pin {} failed: {:?}", self.index, self.kind)
+    }
+}
+
+impl<P: OutputPin> ErrorType for Chain<P> {
+    type Error = PinError;
+}
+
+impl<P: OutputPin> OutputPin for Chain<P> {
+    #[inline]
+    fn set_low(&mut self) -> Result<(), PinError> {
+        self.object
+            .set_low()
+            .map_err(|error| PinError::new(0, error))
+    }
+
+    #[inline]
+    fn set_high(&mut self) -> Result<(), PinError> {
+        self.object
+            .set_high()
+            .map_err(|error| PinError::new(0, error))
+    }
+}
+
+impl<P: StatefulOutputPin> StatefulOutputPin for Chain<P> {
+    #[inline]
+    fn is_set_high(&mut self) -> Result<bool, PinError> {
+        self.object
+            .is_set_high()
+            .map_err(|error| PinError::new(0, error))
+    }
+
+    #[inline]
+    fn is_set_low(&mut self) -> Result<bool, PinError> {
+        self.object
+            .is_set_low()
+            .map_err(|error| PinError::new(0, error))
+    }
+
+    #[inline]
+    fn toggle(&mut self) -> Result<(), PinError> {
+        self.object
+            .toggle()
+            .map_err(|error| PinError::new(0, error))
+    }
+}
+
+impl<P: OutputPin, C: ChainElement + OutputPin<Error = PinError>> ErrorType for Link<P, C> {
+    type Error = PinError;
+}
+
+impl<P: OutputPin, C: ChainElement + OutputPin<Error = PinError>> OutputPin for Link<P, C> {
+    #[inline]
+    fn set_low(&mut self) -> Result<(), PinError> {
+        let parent = self.parent.set_low();
+        let object = self
+            .object
+            .set_low()
+            .map_err(|error| PinError::new(C::LEN, error));
+
+        parent.and(object)
+    }
+
+    #[inline]
+    fn set_high(&mut self) -> Result<(), PinError> {
+        let parent = self.parent.set_high();
+        let object = self
+            .object
+            .set_high()
+            .map_err(|error| PinError::new(C::LEN, error));
+
+        parent.and(object)
+    }
+}
+
+impl<P, C> StatefulOutputPin for Link<P, C>
+where
+    P: StatefulOutputPin,
+    C: ChainElement + StatefulOutputPin + OutputPin<Error = PinError>,
+{
+    #[inline]
+    fn is_set_high(&mut self) -> Result<bool, PinError> {
+        let parent = self.parent.is_set_high()?;
+        let object = self
+            .object
+            .is_set_high()
+            .map_err(|error| PinError::new(C::LEN, error))?;
+
+        Ok(parent && object)
+    }
+
+    #[inline]
+    fn is_set_low(&mut self) -> Result<bool, PinError> {
+        let parent = self.parent.is_set_low()?;
+        let object = self
+            .object
+            .is_set_low()
+            .map_err(|error| PinError::new(C::LEN, error))?;
+
+        Ok(parent && object)
+    }
+
+    #[inline]
+    fn toggle(&mut self) -> Result<(), PinError> {
+        let parent = self.parent.toggle();
+        let object = self
+            .object
+            .toggle()
+            .map_err(|error| PinError::new(C::LEN, error));
+
+        parent.and(object)
+    }
+}
+
+impl<P: OutputPin> private::WriteBits for Chain<P> {
+    #[inline]
+    fn write_bits(&mut self, value: u8) -> Result<(), PinError> {
+        self.object
+            .set_state((value & 1 != 0).into())
+            .map_err(|error| PinError::new(0, error))
+    }
+}
+
+impl<P: OutputPin, C: ChainElement + private::WriteBits> private::WriteBits for Link<P, C> {
+    #[inline]
+    fn write_bits(&mut self, value: u8) -> Result<(), PinError> {
+        let parent = self.parent.write_bits(value);
+        let object = self
+            .object
+            .set_state((value & (1 << C::LEN) != 0).into())
+            .map_err(|error| PinError::new(C::LEN, error));
+
+        parent.and(object)
+    }
+}
+
+/// A parallel bus of `N` output pins, e.g. the data lines of an HD44780 or 8080-style display.
+///
+/// The pins can have different types. The first appended pin is driven by the least significant
+/// bit of the written value. The number of pins must be equal to `N`, and `N` can be at most 8,
+/// which is checked at compile time.
+///
+/// # Example:
+///
+/// ```rust,ignore
+/// let pins = Chain::new(d0).append(d1).append(d2).append(d3);
+/// let mut bus = ParallelBus::<_, 4>::new(pins);
+///
+/// bus.write(0b1010)?;
+/// ```
+pub struct ParallelBus<C, const N: usize = 8> {
+    pins: C,
+}
+
+impl<C, const N: usize> ParallelBus<C, N>
+where
+    C: ExactLength<N> + private::WriteBits,
+{
+    /// Creates a bus from a chain of pins.
+    #[inline]
+    pub fn new(pins: C) -> Self {
+        const { assert!(N <= 8, "a parallel bus can have at most 8 pins") };
+
+        Self { pins }
+    }
+
+    /// Drives every pin to the level of the corresponding bit of `value`.
+    ///
+    /// The bits above the `N`th one are ignored. If a pin fails, the remaining pins are still
+    /// driven, and the error of the first failing pin is returned.
+    #[inline]
+    pub fn write(&mut self, value: u8) -> Result<(), PinError> {
+        self.pins.write_bits(value)
+    }
+
+    /// Returns the chain of pins.
+    #[inline]
+    pub fn release(self) -> C {
+        self.pins
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement, ParallelBus, PinError};
+    use ::embedded_hal::digital::{ErrorKind, ErrorType, OutputPin, StatefulOutputPin};
+
+    struct Pin {
+        high: bool,
+        broken: bool,
+    }
+
+    impl Pin {
+        fn new(high: bool) -> Self {
+            Self {
+                high,
+                broken: false,
+            }
+        }
+    }
+
+    impl ErrorType for Pin {
+        type Error = ErrorKind;
+    }
+
+    impl OutputPin for Pin {
+        fn set_low(&mut self) -> Result<(), ErrorKind> {
+            self.high = false;
+            if self.broken {
+                Err(ErrorKind::Other)
+            } else {
+                Ok(())
+            }
+        }
+
+        fn set_high(&mut self) -> Result<(), ErrorKind> {
+            self.high = true;
+            if self.broken {
+                Err(ErrorKind::Other)
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl StatefulOutputPin for Pin {
+        fn is_set_high(&mut self) -> Result<bool, ErrorKind> {
+            Ok(self.high)
+        }
+
+        fn is_set_low(&mut self) -> Result<bool, ErrorKind> {
+            Ok(!self.high)
+        }
+    }
+
+    #[test]
+    pub fn test_fan_out() {
+        let mut pins = Chain::new(Pin::new(false))
+            .append(Pin::new(true))
+            .append(Pin::new(false));
+
+        assert!(!pins.is_set_high().unwrap());
+        assert!(!pins.is_set_low().unwrap());
+
+        pins.set_high().unwrap();
+        assert!(pins.is_set_high().unwrap());
+
+        pins.toggle().unwrap();
+        assert!(pins.is_set_low().unwrap());
+    }
+
+    #[test]
+    pub fn test_parallel_bus() {
+        let pins = Chain::new(Pin::new(false))
+            .append(Pin::new(true))
+            .append(Pin::new(false));
+        let mut bus = ParallelBus::<_, 3>::new(pins);
+
+        bus.write(0b1101).unwrap();
+
+        let pins = bus.release();
+        assert!(pins.parent.parent.object.high);
+        assert!(!pins.parent.object.high);
+        assert!(pins.object.high);
+    }
+
+    #[test]
+    pub fn test_fan_out_error() {
+        let mut broken = Pin::new(false);
+        broken.broken = true;
+        let mut pins = Chain::new(Pin::new(false))
+            .append(broken)
+            .append(Pin::new(false));
+
+        assert_eq!(
+            Err(PinError {
+                index: 1,
+                kind: ErrorKind::Other
+            }),
+            pins.set_high()
+        );
+        assert!(pins.is_set_high().unwrap());
+    }
+}
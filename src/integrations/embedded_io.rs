@@ -0,0 +1,181 @@
+//! Broadcasting writes to a chain of `embedded-io` writers.
+//!
+//! A chain of [`Write`] objects is itself a [`Write`], which writes every buffer to all of its
+//! objects, in the order they were appended. This can be used to send the same output to multiple
+//! sinks, e.g. to a UART and a log buffer.
+//!
+//! Every writer receives the whole buffer, using [`Write::write_all`]. If a writer fails, the
+//! remaining writers still receive the data, and the error of the first failing writer is
+//! returned, as a [`BroadcastError`].
+
+use core::fmt::{self, Display, Formatter};
+
+use ::embedded_io::{Error, ErrorKind, ErrorType, Write};
+
+use crate::{Chain, ChainElement, Link};
+
+/// The error returned by a chain of writers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BroadcastError {
+    /// The position of the first writer that failed, in the order they were appended.
+    pub index: usize,
+
+    /// The kind of the error that the writer returned.
+    pub kind: ErrorKind,
+}
+
+impl BroadcastError {
+    #[inline]
+    fn new(index: usize, error: impl Error) -> Self {
+        Self {
+            index,
+            kind: error.kind(),
+        }
+    }
+}
+
+impl Error for BroadcastError {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl Display for BroadcastError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "writer {} failed: {:?}", self.index, self.kind)
+    }
+}
+
+impl<W: Write> ErrorType for Chain<W> {
+    type Error = BroadcastError;
+}
+
+impl<W: Write> Write for Chain<W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, BroadcastError> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), BroadcastError> {
+        self.object
+            .write_all(buf)
+            .map_err(|error| BroadcastError::new(0, error))
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), BroadcastError> {
+        self.object
+            .flush()
+            .map_err(|error| BroadcastError::new(0, error))
+    }
+}
+
+impl<W: Write, C: ChainElement + Write<Error = BroadcastError>> ErrorType for Link<W, C> {
+    type Error = BroadcastError;
+}
+
+impl<W: Write, C: ChainElement + Write<Error = BroadcastError>> Write for Link<W, C> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize, BroadcastError> {
+        self.write_all(buf)?;
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> Result<(), BroadcastError> {
+        let parent = self.parent.write_all(buf);
+        let object = self
+            .object
+            .write_all(buf)
+            .map_err(|error| BroadcastError::new(C::LEN, error));
+
+        parent.and(object)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<(), BroadcastError> {
+        let parent = self.parent.flush();
+        let object = self
+            .object
+            .flush()
+            .map_err(|error| BroadcastError::new(C::LEN, error));
+
+        parent.and(object)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{BroadcastError, Chain, ChainElement};
+    use ::embedded_io::{ErrorKind, ErrorType, Write};
+
+    struct Buffer {
+        data: [u8; 8],
+        len: usize,
+    }
+
+    impl Buffer {
+        fn new() -> Self {
+            Self {
+                data: [0; 8],
+                len: 0,
+            }
+        }
+
+        fn contents(&self) -> &[u8] {
+            &self.data[..self.len]
+        }
+    }
+
+    impl ErrorType for Buffer {
+        type Error = ErrorKind;
+    }
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> Result<usize, ErrorKind> {
+            let count = buf.len().min(self.data.len() - self.len);
+            if count == 0 && !buf.is_empty() {
+                return Err(ErrorKind::OutOfMemory);
+            }
+            self.data[self.len..][..count].copy_from_slice(&buf[..count]);
+            self.len += count;
+            Ok(count)
+        }
+
+        fn flush(&mut self) -> Result<(), ErrorKind> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn test_broadcast() {
+        let mut sinks = Chain::new(Buffer::new()).append(Buffer::new());
+
+        write!(sinks, "{}-{}", 1, 2).unwrap();
+        sinks.flush().unwrap();
+
+        assert_eq!(b"1-2", sinks.parent.object.contents());
+        assert_eq!(b"1-2", sinks.object.contents());
+    }
+
+    #[test]
+    pub fn test_broadcast_error() {
+        let mut full = Buffer::new();
+        full.len = 7;
+        let mut sinks = Chain::new(Buffer::new()).append(full).append(Buffer::new());
+
+        assert_eq!(
+            Err(BroadcastError {
+                index: 1,
+                kind: ErrorKind::OutOfMemory
+            }),
+            sinks.write_all(b"abc")
+        );
+
+        assert_eq!(b"abc", sinks.parent.parent.object.contents());
+        assert_eq!(b"abc", sinks.object.contents());
+    }
+}
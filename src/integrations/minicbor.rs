@@ -0,0 +1,162 @@
+//! Encoding and decoding chains using `minicbor`.
+//!
+//! A chain is encoded as a CBOR array of its objects, in the order they were appended. Decoding
+//! accepts both definite and indefinite length arrays, but fails if the number of elements is not
+//! equal to the length of the chain.
+
+use ::minicbor::{
+    data::Type,
+    decode::{self, Decode, Decoder},
+    encode::{self, Encode, Encoder, Write},
+};
+
+use crate::{private, Chain, ChainElement, Link};
+
+const LENGTH_MISMATCH: &str = "the number of array elements is not equal to the chain length";
+
+impl<Ctx, V: Encode<Ctx>> Encode<Ctx> for Chain<V> {
+    #[inline]
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        ctx: &mut Ctx,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.array(Self::LEN as u64)?;
+        private::EncodeObjects::encode_objects(self, e, ctx)
+    }
+}
+
+impl<Ctx, V, C> Encode<Ctx> for Link<V, C>
+where
+    V: Encode<Ctx>,
+    C: ChainElement + private::EncodeObjects<Ctx>,
+{
+    #[inline]
+    fn encode<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        ctx: &mut Ctx,
+    ) -> Result<(), encode::Error<W::Error>> {
+        e.array(Self::LEN as u64)?;
+        private::EncodeObjects::encode_objects(self, e, ctx)
+    }
+}
+
+impl<Ctx, V: Encode<Ctx>> private::EncodeObjects<Ctx> for Chain<V> {
+    #[inline]
+    fn encode_objects<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        ctx: &mut Ctx,
+    ) -> Result<(), encode::Error<W::Error>> {
+        self.object.encode(e, ctx)
+    }
+}
+
+impl<Ctx, V, C> private::EncodeObjects<Ctx> for Link<V, C>
+where
+    V: Encode<Ctx>,
+    C: ChainElement + private::EncodeObjects<Ctx>,
+{
+    #[inline]
+    fn encode_objects<W: Write>(
+        &self,
+        e: &mut Encoder<W>,
+        ctx: &mut Ctx,
+    ) -> Result<(), encode::Error<W::Error>> {
+        self.parent.encode_objects(e, ctx)?;
+        self.object.encode(e, ctx)
+    }
+}
+
+fn decode_array<'b, Ctx, C>(d: &mut Decoder<'b>, ctx: &mut Ctx) -> Result<C, decode::Error>
+where
+    C: ChainElement + private::DecodeObjects<'b, Ctx>,
+{
+    let position = d.position();
+    match d.array()? {
+        Some(len) if len == C::LEN as u64 => C::decode_objects(d, ctx),
+        Some(_) => Err(decode::Error::message(LENGTH_MISMATCH).at(position)),
+        None => {
+            let chain = C::decode_objects(d, ctx)?;
+            if d.datatype()? != Type::Break {
+                return Err(decode::Error::message(LENGTH_MISMATCH).at(position));
+            }
+            d.skip()?;
+            Ok(chain)
+        }
+    }
+}
+
+impl<'b, Ctx, V: Decode<'b, Ctx>> Decode<'b, Ctx> for Chain<V> {
+    #[inline]
+    fn decode(d: &mut Decoder<'b>, ctx: &mut Ctx) -> Result<Self, decode::Error> {
+        decode_array(d, ctx)
+    }
+}
+
+impl<'b, Ctx, V, C> Decode<'b, Ctx> for Link<V, C>
+where
+    V: Decode<'b, Ctx>,
+    C: ChainElement + private::DecodeObjects<'b, Ctx>,
+{
+    #[inline]
+    fn decode(d: &mut Decoder<'b>, ctx: &mut Ctx) -> Result<Self, decode::Error> {
+        decode_array(d, ctx)
+    }
+}
+
+impl<'b, Ctx, V: Decode<'b, Ctx>> private::DecodeObjects<'b, Ctx> for Chain<V> {
+    #[inline]
+    fn decode_objects(d: &mut Decoder<'b>, ctx: &mut Ctx) -> Result<Self, decode::Error> {
+        V::decode(d, ctx).map(Chain::new)
+    }
+}
+
+impl<'b, Ctx, V, C> private::DecodeObjects<'b, Ctx> for Link<V, C>
+where
+    V: Decode<'b, Ctx>,
+    C: ChainElement + private::DecodeObjects<'b, Ctx>,
+{
+    #[inline]
+    fn decode_objects(d: &mut Decoder<'b>, ctx: &mut Ctx) -> Result<Self, decode::Error> {
+        let parent = C::decode_objects(d, ctx)?;
+        let object = V::decode(d, ctx)?;
+        Ok(Link { parent, object })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement, Link};
+
+    type Message<'a> = Link<&'a str, Link<bool, Chain<u8>>>;
+
+    #[test]
+    pub fn test_encode() {
+        let chain: Message<'_> = Chain::new(1u8).append(true).append("hi");
+
+        let mut buffer = [0; 8];
+        ::minicbor::encode(chain, buffer.as_mut()).unwrap();
+
+        assert_eq!([0x83, 0x01, 0xf5, 0x62, b'h', b'i'], buffer[..6]);
+    }
+
+    #[test]
+    pub fn test_decode() {
+        let decoded: Message<'_> =
+            ::minicbor::decode(&[0x83, 0x01, 0xf5, 0x62, b'h', b'i']).unwrap();
+        assert_eq!(Chain::new(1u8).append(true).append("hi"), decoded);
+
+        let indefinite: Message<'_> =
+            ::minicbor::decode(&[0x9f, 0x01, 0xf5, 0x62, b'h', b'i', 0xff]).unwrap();
+        assert_eq!(decoded, indefinite);
+    }
+
+    #[test]
+    pub fn test_decode_length_mismatch() {
+        assert!(::minicbor::decode::<Message<'_>>(&[0x82, 0x01, 0xf5]).is_err());
+        assert!(::minicbor::decode::<Message<'_>>(&[0x84, 0x01, 0xf5, 0x60, 0x02]).is_err());
+        assert!(::minicbor::decode::<Message<'_>>(&[0x9f, 0x01, 0xf5, 0x60, 0x02, 0xff]).is_err());
+    }
+}
@@ -0,0 +1,51 @@
+//! Implementations of third party traits.
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+
+#[cfg(feature = "critical-section")]
+mod critical_section;
+
+#[cfg(feature = "embassy")]
+mod embassy;
+
+#[cfg(feature = "embedded-dma")]
+mod embedded_dma;
+
+#[cfg(feature = "embedded-hal")]
+mod embedded_hal;
+
+#[cfg(feature = "embedded-io")]
+mod embedded_io;
+
+#[cfg(feature = "minicbor")]
+mod minicbor;
+
+#[cfg(feature = "proptest")]
+mod proptest;
+
+#[cfg(feature = "tower-layer")]
+mod tower;
+
+#[cfg(feature = "ufmt")]
+mod ufmt;
+
+#[cfg(feature = "usb-device")]
+mod usb_device;
+
+#[cfg(feature = "zeroize")]
+mod zeroize;
+
+#[cfg(feature = "critical-section")]
+pub use self::critical_section::{InMutex, SharedChain};
+#[cfg(feature = "embassy")]
+pub use self::embassy::{SpawnChain, SpawnInto};
+#[cfg(feature = "embedded-dma")]
+pub use self::embedded_dma::{ReadBufferChain, WriteBufferChain};
+#[cfg(feature = "embedded-hal")]
+pub use self::embedded_hal::{ParallelBus, PinError};
+#[cfg(feature = "embedded-io")]
+pub use self::embedded_io::BroadcastError;
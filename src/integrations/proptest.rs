@@ -0,0 +1,59 @@
+//! Generating chains for property tests using `proptest`.
+//!
+//! Every object is generated by its own [`Arbitrary`] strategy, and shrinking works on each object
+//! independently.
+
+use ::proptest::{
+    arbitrary::Arbitrary,
+    strategy::{Map, Strategy},
+};
+
+use crate::{private, Chain, ChainElement, Link};
+
+impl<V: Arbitrary> Arbitrary for Chain<V> {
+    type Parameters = V::Parameters;
+    type Strategy = Map<V::Strategy, fn(V) -> Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        V::arbitrary_with(args).prop_map(Chain::new)
+    }
+}
+
+impl<V, C> Arbitrary for Link<V, C>
+where
+    V: Arbitrary,
+    C: ChainElement + private::DebugObjects + Arbitrary,
+{
+    /// The parameters of the parent chain's and the object's strategies.
+    type Parameters = (C::Parameters, V::Parameters);
+    type Strategy = Map<(C::Strategy, V::Strategy), fn((C, V)) -> Self>;
+
+    fn arbitrary_with((parent, object): Self::Parameters) -> Self::Strategy {
+        (C::arbitrary_with(parent), V::arbitrary_with(object))
+            .prop_map(|(parent, object)| Link { parent, object })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, Link};
+    use ::proptest::{prelude::*, sample::SizeRange, test_runner::TestRunner};
+
+    extern crate std;
+    use std::vec::Vec;
+
+    #[test]
+    pub fn test_arbitrary_with() {
+        let mut runner = TestRunner::deterministic();
+
+        let strategy =
+            <Link<Vec<u8>, Chain<bool>>>::arbitrary_with(((), (SizeRange::from(2..4), ())));
+
+        runner
+            .run(&strategy, |chain| {
+                prop_assert!((2..4).contains(&chain.object.len()));
+                Ok(())
+            })
+            .unwrap();
+    }
+}
@@ -0,0 +1,53 @@
+//! Composing `tower` middleware using chains.
+//!
+//! A chain of [`Layer`]s is itself a [`Layer`]. The first appended layer is the outermost one,
+//! just like with `tower::ServiceBuilder`, so a request passes through the layers in the order
+//! they were appended. Calling [`Layer::layer`] on the chain collapses it into a single service.
+//!
+//! The `tower-layer` crate requires `std`.
+
+use ::tower_layer::Layer;
+
+use crate::{Chain, ChainElement, Link};
+
+impl<S, L: Layer<S>> Layer<S> for Chain<L> {
+    type Service = L::Service;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        self.object.layer(inner)
+    }
+}
+
+impl<S, L, C> Layer<S> for Link<L, C>
+where
+    L: Layer<S>,
+    C: ChainElement + Layer<L::Service>,
+{
+    type Service = C::Service;
+
+    #[inline]
+    fn layer(&self, inner: S) -> Self::Service {
+        self.parent.layer(self.object.layer(inner))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement};
+    use ::tower_layer::{layer_fn, Layer};
+
+    struct Service(u32);
+
+    #[test]
+    pub fn test_layer_order() {
+        let layers = Chain::new(layer_fn(|inner: Service| Service(inner.0 * 10 + 1)))
+            .append(layer_fn(|inner: Service| Service(inner.0 * 10 + 2)))
+            .append(layer_fn(|inner: Service| Service(inner.0 * 10 + 3)));
+
+        // The outermost layer is applied last.
+        let service = layers.layer(Service(0));
+
+        assert_eq!(321, service.0);
+    }
+}
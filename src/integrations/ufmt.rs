@@ -0,0 +1,109 @@
+//! Formatting chains using `ufmt`.
+//!
+//! [`uDebug`] formats a chain as a list of its objects, in the order they were appended, just like
+//! `core::fmt::Debug`. [`uDisplay`] writes the objects one after the other, without separators, so
+//! that a chain of text fragments and values can be printed as a single message.
+
+use ::ufmt::{uDebug, uDisplay, uWrite, DebugList, Formatter};
+
+use crate::{
+    private::{self, UfmtDebugObjects},
+    Chain, ChainElement, Link,
+};
+
+impl<V: uDebug + ?Sized> uDebug for Chain<V> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        let mut list = f.debug_list()?;
+        self.list_objects(&mut list)?;
+        list.finish()
+    }
+}
+
+impl<V: uDebug + ?Sized, C: ChainElement + private::UfmtDebugObjects> uDebug for Link<V, C> {
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        let mut list = f.debug_list()?;
+        self.list_objects(&mut list)?;
+        list.finish()
+    }
+}
+
+impl<V: uDebug + ?Sized> private::UfmtDebugObjects for Chain<V> {
+    fn list_objects<W: uWrite + ?Sized>(
+        &self,
+        list: &mut DebugList<'_, '_, W>,
+    ) -> Result<(), W::Error> {
+        list.entry(&&self.object)?;
+        Ok(())
+    }
+}
+
+impl<V, C> private::UfmtDebugObjects for Link<V, C>
+where
+    V: uDebug + ?Sized,
+    C: ChainElement + private::UfmtDebugObjects,
+{
+    fn list_objects<W: uWrite + ?Sized>(
+        &self,
+        list: &mut DebugList<'_, '_, W>,
+    ) -> Result<(), W::Error> {
+        self.parent.list_objects(list)?;
+        list.entry(&&self.object)?;
+        Ok(())
+    }
+}
+
+impl<V: uDisplay + ?Sized> uDisplay for Chain<V> {
+    #[inline]
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        self.object.fmt(f)
+    }
+}
+
+impl<V: uDisplay + ?Sized, C: ChainElement + uDisplay> uDisplay for Link<V, C> {
+    #[inline]
+    fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        self.parent.fmt(f)?;
+        self.object.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement};
+    use ::ufmt::{uWrite, uwrite};
+    use core::convert::Infallible;
+
+    extern crate std;
+    use std::string::String;
+
+    struct Serial(String);
+
+    impl uWrite for Serial {
+        type Error = Infallible;
+
+        fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+            self.0.push_str(s);
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn test_udebug() {
+        let chain = Chain::new(1u8).append(Some(2u16)).append(-3i32);
+
+        let mut serial = Serial(String::new());
+        uwrite!(serial, "{:?}", chain).unwrap();
+
+        assert_eq!("[1, Some(2), -3]", serial.0);
+    }
+
+    #[test]
+    pub fn test_udisplay() {
+        let chain = Chain::new("T=").append(21u8).append(" C");
+
+        let mut serial = Serial(String::new());
+        uwrite!(serial, "{}", chain).unwrap();
+
+        assert_eq!("T=21 C", serial.0);
+    }
+}
@@ -0,0 +1,113 @@
+//! Using chains of `usb-device` classes in composite devices.
+//!
+//! [`UsbDevice::poll`] takes the classes of the device as a slice of `&mut dyn UsbClass<B>`.
+//! [`Erase`] is implemented for `dyn UsbClass<B>`, so the classes can be kept in a chain with
+//! their concrete types, and converted into an array of trait objects before polling:
+//!
+//! ```rust,ignore
+//! let mut classes = Chain::new(serial).append(keyboard);
+//!
+//! let mut list: [&mut dyn UsbClass<_>; 2] = classes.as_dyn_mut().into_array();
+//! device.poll(&mut list);
+//! ```
+//!
+//! [`UsbDevice::poll`]: ::usb_device::device::UsbDevice::poll
+
+use ::usb_device::{bus::UsbBus, class::UsbClass};
+
+use crate::Erase;
+
+impl<'a, B: UsbBus, T: UsbClass<B> + 'a> Erase<T> for dyn UsbClass<B> + 'a {
+    #[inline]
+    fn erase(item: &T) -> &Self {
+        item
+    }
+
+    #[inline]
+    fn erase_mut(item: &mut T) -> &mut Self {
+        item
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement};
+    use ::usb_device::{
+        bus::{PollResult, UsbBus},
+        class::UsbClass,
+        endpoint::{EndpointAddress, EndpointType},
+        Result, UsbDirection, UsbError,
+    };
+
+    struct Bus;
+
+    impl UsbBus for Bus {
+        fn alloc_ep(
+            &mut self,
+            _ep_dir: UsbDirection,
+            _ep_addr: Option<EndpointAddress>,
+            _ep_type: EndpointType,
+            _max_packet_size: u16,
+            _interval: u8,
+        ) -> Result<EndpointAddress> {
+            Err(UsbError::Unsupported)
+        }
+
+        fn enable(&mut self) {}
+
+        fn reset(&self) {}
+
+        fn set_device_address(&self, _addr: u8) {}
+
+        fn write(&self, _ep_addr: EndpointAddress, _buf: &[u8]) -> Result<usize> {
+            Err(UsbError::Unsupported)
+        }
+
+        fn read(&self, _ep_addr: EndpointAddress, _buf: &mut [u8]) -> Result<usize> {
+            Err(UsbError::Unsupported)
+        }
+
+        fn set_stalled(&self, _ep_addr: EndpointAddress, _stalled: bool) {}
+
+        fn is_stalled(&self, _ep_addr: EndpointAddress) -> bool {
+            false
+        }
+
+        fn suspend(&self) {}
+
+        fn resume(&self) {}
+
+        fn poll(&self) -> PollResult {
+            PollResult::None
+        }
+    }
+
+    struct Class(u8);
+
+    impl UsbClass<Bus> for Class {
+        fn reset(&mut self) {
+            self.0 += 1;
+        }
+    }
+
+    struct OtherClass(bool);
+
+    impl UsbClass<Bus> for OtherClass {
+        fn reset(&mut self) {
+            self.0 = true;
+        }
+    }
+
+    #[test]
+    pub fn test_class_list() {
+        let mut classes = Chain::new(Class(0)).append(OtherClass(false));
+
+        let list: [&mut dyn UsbClass<Bus>; 2] = classes.as_dyn_mut().into_array();
+        for class in list {
+            class.reset();
+        }
+
+        assert_eq!(1, classes.parent.object.0);
+        assert!(classes.object.0);
+    }
+}
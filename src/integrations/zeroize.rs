@@ -0,0 +1,64 @@
+//! Wiping chains using `zeroize`.
+//!
+//! Wrap a chain in [`zeroize::Zeroizing`] to wipe every object when the chain is dropped.
+
+use ::zeroize::Zeroize;
+
+use crate::{Chain, ChainElement, Link};
+
+impl<V: Zeroize> Zeroize for Chain<V> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.object.zeroize();
+    }
+}
+
+impl<V: Zeroize, C: ChainElement + Zeroize> Zeroize for Link<V, C> {
+    #[inline]
+    fn zeroize(&mut self) {
+        self.parent.zeroize();
+        self.object.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+
+    use crate::{Chain, ChainElement};
+    use ::zeroize::{Zeroize, Zeroizing};
+
+    #[test]
+    pub fn test_zeroize() {
+        let mut chain = Chain::new([1u8; 4]).append(2u32).append([3u16; 2]);
+
+        chain.zeroize();
+
+        assert_eq!([0; 4], chain.parent.parent.object);
+        assert_eq!(0, chain.parent.object);
+        assert_eq!([0; 2], chain.object);
+    }
+
+    #[test]
+    pub fn test_zeroizing_wrapper() {
+        struct Key<'a> {
+            wiped: &'a Cell<bool>,
+        }
+
+        impl Zeroize for Key<'_> {
+            fn zeroize(&mut self) {
+                self.wiped.set(true);
+            }
+        }
+
+        let wiped = Cell::new(false);
+        let mut chain = Zeroizing::new(Chain::new([1u8; 4]).append(Key { wiped: &wiped }));
+
+        chain.parent.object.zeroize();
+        assert_eq!([0; 4], chain.parent.object);
+        assert!(!wiped.get());
+
+        drop(chain);
+        assert!(wiped.get());
+    }
+}
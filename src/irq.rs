@@ -0,0 +1,118 @@
+//! Routing interrupts to chain objects.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A handler of the interrupt with the number [`IrqHandler::IRQ`].
+pub trait IrqHandler {
+    /// The number of the handled interrupt.
+    const IRQ: u16;
+
+    /// Handles the interrupt.
+    fn on_irq(&mut self);
+}
+
+/// A chain of [`IrqHandler`] objects, used as an interrupt dispatch table.
+///
+/// The interrupt numbers are constants, so [`IrqChain::handle_irq`] compiles to a series of
+/// comparisons against constants, similar to a `match` on the interrupt number.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, IrqChain, IrqHandler};
+///
+/// struct Timer(u32);
+/// struct Uart(u32);
+///
+/// impl IrqHandler for Timer {
+///     const IRQ: u16 = 28;
+///
+///     fn on_irq(&mut self) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// impl IrqHandler for Uart {
+///     const IRQ: u16 = 37;
+///
+///     fn on_irq(&mut self) {
+///         self.0 += 1;
+///     }
+/// }
+///
+/// let mut handlers = Chain::new(Timer(0)).append(Uart(0));
+///
+/// assert!(handlers.handle_irq(37));
+/// assert!(!handlers.handle_irq(38));
+/// assert_eq!(1, handlers.object.0);
+/// ```
+pub trait IrqChain: ChainElement {
+    /// Calls every handler of the interrupt `irq`, in the order they were appended.
+    ///
+    /// Multiple handlers may share an interrupt number, e.g. when a single interrupt line
+    /// serves multiple peripherals. Returns whether any handler was called.
+    fn handle_irq(&mut self, irq: u16) -> bool;
+}
+
+impl<H: IrqHandler> IrqChain for Chain<H> {
+    #[inline]
+    fn handle_irq(&mut self, irq: u16) -> bool {
+        if H::IRQ == irq {
+            self.object.on_irq();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<H: IrqHandler, C: IrqChain> IrqChain for Link<H, C> {
+    #[inline]
+    fn handle_irq(&mut self, irq: u16) -> bool {
+        let handled = self.parent.handle_irq(irq);
+        if H::IRQ == irq {
+            self.object.on_irq();
+            true
+        } else {
+            handled
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Exti<const LINE: u8>(u32);
+
+    impl<const LINE: u8> IrqHandler for Exti<LINE> {
+        const IRQ: u16 = 23;
+
+        fn on_irq(&mut self) {
+            self.0 += 1;
+        }
+    }
+
+    struct Dma(u32);
+
+    impl IrqHandler for Dma {
+        const IRQ: u16 = 11;
+
+        fn on_irq(&mut self) {
+            self.0 += 1;
+        }
+    }
+
+    #[test]
+    pub fn test_handle_irq() {
+        let mut handlers = Chain::new(Exti::<5>(0)).append(Dma(0)).append(Exti::<9>(0));
+
+        assert!(handlers.handle_irq(23));
+        assert!(handlers.handle_irq(11));
+        assert!(!handlers.handle_irq(0));
+
+        assert_eq!(1, handlers.parent.parent.object.0);
+        assert_eq!(1, handlers.parent.object.0);
+        assert_eq!(1, handlers.object.0);
+    }
+}
@@ -0,0 +1,159 @@
+//! Looking up chain objects by a constant key.
+
+use crate::{Chain, ChainElement, Link};
+
+/// Assigns a constant key to a type, used by [`KeyedChain`] to look up objects at runtime.
+pub trait KeyedElement {
+    /// The key of the type.
+    const KEY: &'static str;
+}
+
+/// A chain of [`KeyedElement`] objects.
+///
+/// Objects are usually looked up through a common trait object type, using [`DynView::get_by_key`]
+/// or [`DynViewMut::get_by_key_mut`]. This is useful for registries where the keys are only known
+/// at runtime, e.g. when they are received over a serial line, but the objects are stored in a
+/// chain with their concrete types. If multiple objects have the same key, the last appended one
+/// is selected.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{impl_erase, Chain, ChainElement, KeyedElement};
+///
+/// trait Setting {
+///     fn set(&mut self, value: u32);
+///     fn value(&self) -> u32;
+/// }
+///
+/// impl_erase!(dyn Setting);
+///
+/// struct Brightness(u8);
+/// struct Timeout(u32);
+///
+/// impl KeyedElement for Brightness {
+///     const KEY: &'static str = "brightness";
+/// }
+///
+/// impl KeyedElement for Timeout {
+///     const KEY: &'static str = "timeout";
+/// }
+///
+/// impl Setting for Brightness {
+///     fn set(&mut self, value: u32) {
+///         self.0 = value.min(255) as u8;
+///     }
+///
+///     fn value(&self) -> u32 {
+///         self.0 as u32
+///     }
+/// }
+///
+/// impl Setting for Timeout {
+///     fn set(&mut self, value: u32) {
+///         self.0 = value;
+///     }
+///
+///     fn value(&self) -> u32 {
+///         self.0
+///     }
+/// }
+///
+/// let mut settings = Chain::new(Brightness(100)).append(Timeout(30));
+///
+/// if let Some(setting) = settings.as_dyn_mut::<dyn Setting>().get_by_key_mut("brightness") {
+///     setting.set(1000);
+/// }
+///
+/// let settings = settings.as_dyn::<dyn Setting>();
+/// assert_eq!(Some(255), settings.get_by_key("brightness").map(|s| s.value()));
+/// assert!(settings.get_by_key("volume").is_none());
+/// ```
+///
+/// [`DynView::get_by_key`]: crate::DynView::get_by_key
+/// [`DynViewMut::get_by_key_mut`]: crate::DynViewMut::get_by_key_mut
+pub trait KeyedChain: ChainElement {
+    /// Returns the position of the last appended object with the given key, in the order the
+    /// objects were appended.
+    fn position_of_key(key: &str) -> Option<usize>;
+}
+
+impl<V: KeyedElement> KeyedChain for Chain<V> {
+    #[inline]
+    fn position_of_key(key: &str) -> Option<usize> {
+        if V::KEY == key {
+            Some(0)
+        } else {
+            None
+        }
+    }
+}
+
+impl<V: KeyedElement, C: KeyedChain> KeyedChain for Link<V, C> {
+    #[inline]
+    fn position_of_key(key: &str) -> Option<usize> {
+        if V::KEY == key {
+            Some(C::LEN)
+        } else {
+            C::position_of_key(key)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{impl_erase, Chain, ChainElement, KeyedChain, KeyedElement, Link};
+
+    trait Value {
+        fn value(&self) -> u32;
+    }
+
+    impl_erase!(dyn Value);
+
+    struct First(u32);
+    struct Second(u32);
+
+    impl KeyedElement for First {
+        const KEY: &'static str = "first";
+    }
+
+    impl KeyedElement for Second {
+        const KEY: &'static str = "second";
+    }
+
+    impl Value for First {
+        fn value(&self) -> u32 {
+            self.0
+        }
+    }
+
+    impl Value for Second {
+        fn value(&self) -> u32 {
+            self.0
+        }
+    }
+
+    #[test]
+    pub fn test_position_of_key() {
+        type Settings = Link<First, Link<Second, Chain<First>>>;
+
+        assert_eq!(Some(2), Settings::position_of_key("first"));
+        assert_eq!(Some(1), Settings::position_of_key("second"));
+        assert_eq!(None, Settings::position_of_key("third"));
+    }
+
+    #[test]
+    pub fn test_get_by_key() {
+        let mut settings = Chain::new(First(1)).append(Second(2)).append(First(3));
+
+        let view = settings.as_dyn::<dyn Value>();
+        assert_eq!(Some(3), view.get_by_key("first").map(|v| v.value()));
+        assert_eq!(Some(2), view.get_by_key("second").map(|v| v.value()));
+        assert!(view.get_by_key("third").is_none());
+
+        assert!(settings
+            .as_dyn_mut::<dyn Value>()
+            .get_by_key_mut("second")
+            .is_some());
+    }
+}
@@ -0,0 +1,148 @@
+//! Reporting the memory layout of chains in const contexts.
+
+use core::mem::{align_of, size_of};
+
+use crate::{Chain, ChainElement, Link};
+
+/// The size and alignment of an object, in bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ObjectLayout {
+    /// The size of the object, as returned by [`size_of`].
+    pub size: usize,
+
+    /// The alignment of the object, as returned by [`align_of`].
+    pub align: usize,
+}
+
+impl ObjectLayout {
+    /// Returns the layout of `T`.
+    #[inline]
+    pub const fn of<T>() -> Self {
+        Self {
+            size: size_of::<T>(),
+            align: align_of::<T>(),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct LayoutNode {
+    object: ObjectLayout,
+    parent: Option<&'static LayoutNode>,
+}
+
+/// Memory layout information of a chain, available in const contexts.
+///
+/// This trait is implemented for every chain. Use [`object_layouts`] to list the layout of every
+/// object.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{chain, object_layouts, Chain, ChainLayout, Link, ObjectLayout};
+///
+/// type Drivers = chain![u8, u32, u16];
+///
+/// const LAYOUTS: [ObjectLayout; 3] = object_layouts::<Drivers, 3>();
+/// const _: () = assert!(Drivers::SIZE <= 16, "The drivers don't fit into the memory budget");
+///
+/// assert_eq!(ObjectLayout::of::<u32>(), LAYOUTS[1]);
+/// assert_eq!(7, Drivers::OBJECTS_SIZE);
+/// assert_eq!(Drivers::SIZE - 7, Drivers::PADDING);
+/// ```
+pub trait ChainLayout: ChainElement + Sized {
+    /// The size of the chain, in bytes.
+    const SIZE: usize = size_of::<Self>();
+
+    /// The alignment of the chain, in bytes.
+    const ALIGN: usize = align_of::<Self>();
+
+    /// The sum of the sizes of the objects, in bytes.
+    const OBJECTS_SIZE: usize;
+
+    /// The number of padding bytes in the chain.
+    const PADDING: usize = Self::SIZE - Self::OBJECTS_SIZE;
+
+    #[doc(hidden)]
+    const LAYOUT_NODE: LayoutNode;
+}
+
+impl<V> ChainLayout for Chain<V> {
+    const OBJECTS_SIZE: usize = size_of::<V>();
+
+    const LAYOUT_NODE: LayoutNode = LayoutNode {
+        object: ObjectLayout::of::<V>(),
+        parent: None,
+    };
+}
+
+impl<V, C: ChainLayout> ChainLayout for Link<V, C> {
+    const OBJECTS_SIZE: usize = C::OBJECTS_SIZE + size_of::<V>();
+
+    const LAYOUT_NODE: LayoutNode = LayoutNode {
+        object: ObjectLayout::of::<V>(),
+        parent: Some(&C::LAYOUT_NODE),
+    };
+}
+
+/// Returns the layout of every object of the chain `C`, in the order they were appended.
+///
+/// `N` must be equal to the length of the chain, which is checked at compile time.
+pub const fn object_layouts<C: ChainLayout, const N: usize>() -> [ObjectLayout; N] {
+    const { assert!(N == C::LEN, "N must be equal to the length of the chain") };
+
+    let mut layouts = [ObjectLayout { size: 0, align: 0 }; N];
+    let mut node = &C::LAYOUT_NODE;
+    let mut index = N;
+    loop {
+        index -= 1;
+        layouts[index] = node.object;
+        match node.parent {
+            Some(parent) => node = parent,
+            None => return layouts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{chain, object_layouts, Chain, ChainLayout, Link, ObjectLayout};
+
+    #[allow(dead_code)]
+    #[repr(align(8))]
+    struct Aligned([u8; 16]);
+
+    #[test]
+    pub fn test_object_layouts() {
+        type Drivers = chain![u8, Aligned, u16];
+
+        const LAYOUTS: [ObjectLayout; 3] = object_layouts::<Drivers, 3>();
+
+        assert_eq!(
+            [
+                ObjectLayout { size: 1, align: 1 },
+                ObjectLayout { size: 16, align: 8 },
+                ObjectLayout { size: 2, align: 2 },
+            ],
+            LAYOUTS
+        );
+        assert_eq!(32, Drivers::SIZE);
+        assert_eq!(8, Drivers::ALIGN);
+        assert_eq!(19, Drivers::OBJECTS_SIZE);
+        assert_eq!(13, Drivers::PADDING);
+    }
+
+    #[test]
+    pub fn test_append_order_padding() {
+        type Decreasing = chain![u32, u16, u8, u8];
+        type Increasing = chain![u8, u8, u16, u32];
+
+        assert_eq!(16, Decreasing::SIZE);
+        assert_eq!(8, Decreasing::PADDING);
+        assert_eq!(8, Increasing::SIZE);
+        assert_eq!(0, Increasing::PADDING);
+
+        assert_eq!(24, <chain![u64, u8, u8]>::SIZE);
+        assert_eq!(16, <chain![u8, u8, u64]>::SIZE);
+    }
+}
@@ -0,0 +1,88 @@
+//! Constraining the length of chains in function signatures.
+
+use crate::{Chain, ChainElement, Link};
+
+/// Implemented by chains that contain at least `N` objects.
+///
+/// Use this trait to require a minimum chain length in a function signature:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, MinLength};
+///
+/// fn needs_two(chain: &impl MinLength<2>) -> usize {
+///     chain.len()
+/// }
+///
+/// needs_two(&Chain::new(1u8).append(2u16));
+/// ```
+///
+/// ```rust,compile_fail
+/// # use object_chain::{Chain, ChainElement, MinLength};
+/// # fn needs_two(chain: &impl MinLength<2>) -> usize {
+/// #     chain.len()
+/// # }
+/// needs_two(&Chain::new(1u8));
+/// ```
+///
+/// This trait is implemented for `N` up to 32.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not contain at least {N} objects",
+    label = "this chain is too short"
+)]
+pub trait MinLength<const N: usize>: ChainElement {}
+
+/// Implemented by chains that contain exactly `N` objects.
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, ExactLength};
+///
+/// fn needs_three(chain: &impl ExactLength<3>) -> usize {
+///     chain.len()
+/// }
+///
+/// needs_three(&Chain::new(1u8).append(2u16).append(3u32));
+/// ```
+///
+/// This trait is implemented for `N` up to 32.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not contain exactly {N} objects",
+    label = "this chain has the wrong length"
+)]
+pub trait ExactLength<const N: usize>: MinLength<N> {}
+
+impl<C: ChainElement> MinLength<0> for C {}
+impl<V> MinLength<1> for Chain<V> {}
+impl<V, C: MinLength<0>> MinLength<1> for Link<V, C> {}
+
+impl<V> ExactLength<1> for Chain<V> {}
+
+macro_rules! impl_length_bounds {
+    ($(($n:tt $prev:tt))+) => {
+        $(
+            impl<V, C: MinLength<$prev>> MinLength<$n> for Link<V, C> {}
+            impl<V, C: ExactLength<$prev>> ExactLength<$n> for Link<V, C> {}
+        )+
+    };
+}
+
+impl_length_bounds!((2 1) (3 2) (4 3) (5 4) (6 5) (7 6) (8 7) (9 8) (10 9) (11 10) (12 11) (13 12) (14 13) (15 14) (16 15) (17 16) (18 17) (19 18) (20 19) (21 20) (22 21) (23 22) (24 23) (25 24) (26 25) (27 26) (28 27) (29 28) (30 29) (31 30) (32 31));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn at_least_two<C: MinLength<2>>(chain: &C) -> usize {
+        chain.len()
+    }
+
+    fn exactly_two<C: ExactLength<2>>(chain: &C) -> usize {
+        chain.len()
+    }
+
+    #[test]
+    pub fn test_length_bounds() {
+        assert_eq!(2, at_least_two(&Chain::new(0u8).append(1u16)));
+        assert_eq!(3, at_least_two(&Chain::new(0u8).append(1u16).append(2u32)));
+        assert_eq!(2, exactly_two(&Chain::new(0u8).append(1u16)));
+    }
+}
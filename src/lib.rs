@@ -7,45 +7,1268 @@
 //! to query the number of elements, but you can implement a more useful trait for both `Link` and
 //! `Chain` to make this structure more useful. For an example, check the
 //! `test_accessing_elements_with_common_interface` test in the source code.
+//!
+//! # Memory layout
+//!
+//! `Chain` and `Link` are `#[repr(C)]`, so the objects are laid out in the order they were
+//! appended, with padding inserted between objects that have different alignments. Every `Link`
+//! contains its parent chain as a whole, so the padding at the end of the parent can't be reused
+//! by the objects appended later. To minimize padding, append objects in increasing order of
+//! alignment, i.e. the objects with the largest alignment last. The chain never reorders its
+//! objects, see [type-level limitations](#type-level-limitations).
+//!
+//! # Type-level limitations
+//!
+//! Many operations of this crate are computed on the types of the objects at compile time. Stable
+//! Rust can't compare two types, check whether a type implements a trait, or sort types (e.g. by
+//! their names or alignments) in constant expressions or trait resolution, so:
+//!
+//! - Filtering and counting by a predicate requires every object type to state whether it matches
+//!   the predicate, by implementing [`Matches`].
+//! - Sorting and merging chains requires every object type to state its [`SortKey`], and types
+//!   with equal keys are treated as the same type.
+//! - The orphan rule only allows these impls if the predicate or the object type is defined in your
+//!   crate, e.g. `impl Matches<u8> for u16` is rejected.
+//! - Searching objects by their type, e.g. with [`ChainElement::last_of`] and
+//!   [`ChainElement::count_of`], happens at runtime using [`TypeId`](core::any::TypeId). These
+//!   searches are simple enough for the compiler to optimize them away.
+//! - Type names are only available at runtime, because [`core::any::type_name`] can't be called in
+//!   const contexts.
+//! - Objects are never reordered to reduce padding, because types can't be sorted by their
+//!   alignment.
 #![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+// Allows testing the code generated by `object-chain-derive`, which refers to this crate by name.
+#[cfg(test)]
+extern crate self as object_chain;
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use alloc::boxed::Box;
+
+    #[inline(always)]
+    pub fn assert_exact_length<C: crate::ExactLength<N>, const N: usize>(_chain: &C) {}
+
+    #[inline(always)]
+    pub fn first_mismatch<'a, L: crate::private::ChainEq<R>, R>(
+        left: &'a L,
+        right: &'a R,
+    ) -> Option<(usize, &'a dyn core::fmt::Debug, &'a dyn core::fmt::Debug)> {
+        left.first_mismatch(right)
+    }
+
+    #[inline]
+    pub fn split_into_elements<C: crate::private::FillElements<E>, E, const N: usize>(
+        chain: C,
+    ) -> core::array::IntoIter<E, N> {
+        let mut slots = [const { None }; N];
+        chain.fill_elements(&mut slots);
+        IntoIterator::into_iter(slots.map(|slot| match slot {
+            Some(element) => element,
+            None => unreachable!(),
+        }))
+    }
+
+    #[inline(always)]
+    pub fn discriminant_of<T: crate::Discriminant>(_object: &T) -> u16 {
+        T::ID
+    }
+}
+
+mod aggregate;
+mod assert;
+mod bitfield;
+mod bounded;
+mod bus;
+mod cache;
+mod canonical;
+mod command;
+mod concat;
+mod config;
+mod debug;
+mod destructure;
+mod discriminant;
+mod drain;
+mod drop_order;
+mod elements;
+mod elementwise;
+mod erase;
+mod error;
+mod filter;
+mod homogeneous;
+mod integrations;
+mod irq;
+mod keyed;
+mod layout;
+mod length;
+mod lock;
+mod map;
+mod merge;
+mod nat;
+mod order;
+mod peripheral;
+mod persist;
+mod position;
+mod power;
+mod priority;
+mod provide;
+mod refs;
+mod render;
+mod route;
+mod schedule;
+mod sculpt;
+mod select;
+mod state;
+mod statics;
+mod structure;
+mod transaction;
+mod uninit;
+mod unroll;
+mod widget;
+mod wrap;
+mod zip;
+
+pub use crate::aggregate::{
+    BitAnd, BitOr, Max, Measure, MeasureChain, Metric, Min, Monoid, Product, Sum,
+};
+pub use crate::bitfield::{BitField, BitFieldChain};
+pub use crate::bounded::BoundedChain;
+pub use crate::bus::{BusDevice, BusDeviceChain};
+pub use crate::cache::{CacheChain, CacheTier};
+pub use crate::canonical::{Canonicalize, Insert, SortKey};
+pub use crate::command::{Command, CommandChain};
+pub use crate::concat::{AppendTuple, Concat};
+pub use crate::config::{ConfigChain, ConfigSource};
+pub use crate::debug::{type_names, DebugTypes};
+pub use crate::discriminant::Discriminant;
+pub use crate::drain::{Drain, DrainChain};
+pub use crate::drop_order::ReverseDrop;
+pub use crate::elements::ChainEnum;
+pub use crate::elementwise::{
+    AddAssignEach, AddEach, BitAndAssignEach, BitAndEach, BitOrAssignEach, BitOrEach,
+    BitXorAssignEach, BitXorEach, DivAssignEach, DivEach, Elementwise, MulAssignEach, MulEach,
+    RemAssignEach, RemEach, SubAssignEach, SubEach,
+};
+#[cfg(feature = "alloc")]
+pub use crate::erase::{BoxedChain, EraseBox};
+pub use crate::erase::{DynChain, DynIter, DynView, DynViewMut, Erase, ErasedChain};
+pub use crate::error::ChainError;
+pub use crate::filter::{count_of, CountMatching, Filter, Keep, Matches, Partition, Skip};
+pub use crate::homogeneous::Homogeneous;
+#[cfg(feature = "embedded-io")]
+pub use crate::integrations::BroadcastError;
+#[cfg(feature = "critical-section")]
+pub use crate::integrations::{InMutex, SharedChain};
+#[cfg(feature = "embedded-hal")]
+pub use crate::integrations::{ParallelBus, PinError};
+#[cfg(feature = "embedded-dma")]
+pub use crate::integrations::{ReadBufferChain, WriteBufferChain};
+#[cfg(feature = "embassy")]
+pub use crate::integrations::{SpawnChain, SpawnInto};
+pub use crate::irq::{IrqChain, IrqHandler};
+pub use crate::keyed::{KeyedChain, KeyedElement};
+pub use crate::layout::{object_layouts, ChainLayout, ObjectLayout};
+pub use crate::length::{ExactLength, MinLength};
+pub use crate::lock::{GuardChain, LockChain, Lockable};
+pub use crate::map::{MapChain, Mapper, Scan, Scanner, TryMapChain, TryMapper};
+pub use crate::merge::Merge;
+pub use crate::nat::{cmp, Compare, Const, Nat, Succ, ToNat, Zero};
+pub use crate::order::After;
+pub use crate::peripheral::{Peripheral, PeripheralChain};
+pub use crate::persist::{Persist, PersistChain};
+pub use crate::position::{At, AtPath, ChainPrefix, MapAt, SubChain};
+pub use crate::power::{PowerChain, PowerManaged};
+pub use crate::priority::{priority_order, Prioritized, PriorityChain};
+pub use crate::provide::{Provide, Provided, Resolve, Resolved, Stored};
+pub use crate::refs::AsRefs;
+pub use crate::render::{RenderChain, RenderPass};
+pub use crate::route::{Accept, RouteChain};
+pub use crate::schedule::{Scheduled, Task, TaskChain};
+pub use crate::sculpt::{Pluck, Sculpt};
+pub use crate::select::{position_of, Here, Occurrences, Selector, There};
+pub use crate::state::{MachineStatus, StateMachine, StateMachineChain};
+pub use crate::structure::ChainStruct;
+pub use crate::transaction::Transaction;
+pub use crate::uninit::{ChainUninit, UninitChain};
+pub use crate::widget::{Widget, WidgetChain};
+pub use crate::wrap::{InRefCell, WrapEach, Wrapper};
+pub use crate::zip::{Call, Combine, Pair, Unzip, ZipWith};
+#[cfg(feature = "derive")]
+pub use object_chain_derive::Chain;
+
 mod private {
     pub trait Sealed {}
 
-    impl<V> Sealed for super::Chain<V> {}
-    impl<V, C: super::ChainElement> Sealed for super::Link<V, C> {}
-}
+    #[cfg(not(feature = "unsealed"))]
+    impl<V: ?Sized> Sealed for super::Chain<V> {}
+    #[cfg(not(feature = "unsealed"))]
+    impl<V: ?Sized, C: super::ChainElement> Sealed for super::Link<V, C> {}
+
+    #[cfg(feature = "unsealed")]
+    impl<T: ?Sized> Sealed for T {}
+
+    /// Implemented only by `Chain` and `Link`, even if the `unsealed` feature is enabled. Used by
+    /// traits whose safety depends on the layout of the chain.
+    pub trait SealedLayout {}
+
+    impl<V> SealedLayout for super::Chain<V> {}
+    impl<V, C: super::ChainElement> SealedLayout for super::Link<V, C> {}
+
+    pub trait SealedHomogeneous<T> {}
+
+    impl<T> SealedHomogeneous<T> for super::Chain<T> {}
+    impl<T, C: super::Homogeneous<T>> SealedHomogeneous<T> for super::Link<T, C> {}
+
+    pub trait SealedNat {}
+
+    impl SealedNat for crate::Zero {}
+    impl<N: crate::Nat> SealedNat for crate::Succ<N> {}
+
+    /// Implementation of `At` for links, selected by comparing the position with the length of the
+    /// parent.
+    #[diagnostic::on_unimplemented(message = "position out of bounds of chain `{Self}`")]
+    pub trait AtOrdering<P, O> {
+        type Output;
+
+        fn at(&self) -> &Self::Output;
+
+        fn at_mut(&mut self) -> &mut Self::Output;
+    }
+
+    /// Implementation of `MapAt` for links, selected by comparing the position with the length of
+    /// the parent.
+    #[diagnostic::on_unimplemented(message = "position out of bounds of chain `{Self}`")]
+    pub trait MapAtOrdering<P, O>: AtOrdering<P, O> {
+        type Mapped<U>: crate::ChainElement;
+
+        fn map_at<U, F>(self, f: F) -> Self::Mapped<U>
+        where
+            F: FnOnce(Self::Output) -> U;
+    }
+
+    /// Implementation of `SubChain` for ranges ending at `Succ<E>`, selected by comparing the
+    /// start of the range with `E`.
+    #[diagnostic::on_unimplemented(message = "range out of bounds of chain `{Self}`")]
+    pub trait SubChainOrdering<'a, S, E, O> {
+        type Refs: crate::ChainElement;
+
+        fn sub_chain(&'a self) -> Self::Refs;
+    }
+
+    /// Implementation of `ChainPrefix`, selected by comparing the length of the prefix with the
+    /// length of the chain.
+    #[diagnostic::on_unimplemented(message = "prefix longer than chain `{Self}`")]
+    pub trait PrefixOrdering<N, O> {
+        type Prefix: crate::ChainElement;
+
+        fn prefix(&self) -> &Self::Prefix;
+
+        fn prefix_mut(&mut self) -> &mut Self::Prefix;
+    }
+
+    /// Implementation of `chain_enum!`.
+    pub trait FillElements<E> {
+        fn fill_elements(self, slots: &mut [Option<E>]);
+    }
+
+    /// Implementation of `assert_chain_eq!`.
+    pub trait ChainEq<Rhs> {
+        fn first_mismatch<'a>(
+            &'a self,
+            other: &'a Rhs,
+        ) -> Option<(usize, &'a dyn core::fmt::Debug, &'a dyn core::fmt::Debug)>;
+    }
+
+    /// Appends an object to a chain, or creates a chain from `()`.
+    pub trait Push<V> {
+        type Pushed;
+
+        fn push(self, object: V) -> Self::Pushed;
+    }
+
+    /// Implementation of `Filter`, selected by the decision of the object.
+    pub trait FilterStep<V, Acc> {
+        type Output;
+
+        fn step(acc: Acc, object: V) -> Self::Output;
+    }
+
+    /// Implementation of `CountMatching`, selected by the decision of the object.
+    pub trait CountStep {
+        const COUNT: usize;
+    }
+
+    /// Implementation of `Partition`, selected by the decision of the object.
+    pub trait PartitionStep<V, K, R> {
+        type Kept;
+        type Rest;
+
+        fn step(kept: K, rest: R, object: V) -> (Self::Kept, Self::Rest);
+    }
+
+    /// Implementation of `RouteChain`, selected by the decision of the object.
+    pub trait RouteStep<V, E> {
+        fn route_to(object: &mut V, event: &E);
+    }
+
+    /// Implementation of `PriorityChain::for_each_prioritized`, visiting the ranks below `Self`.
+    pub trait VisitRanks<C, D: ?Sized> {
+        fn visit_ranks<F: FnMut(&mut D)>(chain: &mut C, f: &mut F);
+    }
+
+    /// Implementation of `Insert`, selected by comparing the key of the inserted object with the
+    /// key of the last object.
+    pub trait InsertOrdering<V, O> {
+        type Inserted: crate::ChainElement;
+
+        fn insert_ordered(self, object: V) -> Self::Inserted;
+    }
+
+    /// Decides whether an object with the key `K` is kept by `Merge`, i.e. no object of `Self`
+    /// has the key `K`.
+    pub trait Shadows<K> {
+        type Decision;
+    }
+
+    /// Implementation of `Shadows`, selected by comparing `K` with the key of the last object.
+    /// `D` is the decision of the previous objects.
+    pub trait ShadowOrdering<D> {
+        type Decision;
+    }
+
+    /// Implementation of `Merge`, keeping the objects whose keys don't appear in `Rhs`.
+    pub trait Unshadowed<Rhs> {
+        type Kept;
+
+        fn unshadowed(self) -> Self::Kept;
+    }
+
+    /// Appends every object of `C` to a chain, or creates a chain from `()`.
+    pub trait PushChain<C> {
+        type Pushed: crate::ChainElement;
+
+        fn push_chain(self, chain: C) -> Self::Pushed;
+    }
+
+    /// Drops the objects of a chain, starting with the last one.
+    pub trait DropInReverse {
+        /// # Safety
+        ///
+        /// The objects must not be used or dropped again afterwards.
+        unsafe fn drop_in_reverse(&mut self);
+    }
+
+    /// Implementation of `Resolve` for providers.
+    pub trait ProvidedBy<T, I> {
+        fn provide_object(&self) -> T;
+    }
+
+    pub trait DebugObjects {
+        fn list_objects(&self, list: &mut core::fmt::DebugList<'_, '_>);
+    }
+
+    #[cfg(feature = "ufmt")]
+    pub trait UfmtDebugObjects {
+        fn list_objects<W: ufmt::uWrite + ?Sized>(
+            &self,
+            list: &mut ufmt::DebugList<'_, '_, W>,
+        ) -> Result<(), W::Error>;
+    }
+
+    #[cfg(feature = "minicbor")]
+    pub trait EncodeObjects<Ctx> {
+        fn encode_objects<W: minicbor::encode::Write>(
+            &self,
+            e: &mut minicbor::Encoder<W>,
+            ctx: &mut Ctx,
+        ) -> Result<(), minicbor::encode::Error<W::Error>>;
+    }
+
+    #[cfg(feature = "minicbor")]
+    pub trait DecodeObjects<'b, Ctx>: Sized {
+        fn decode_objects(
+            d: &mut minicbor::Decoder<'b>,
+            ctx: &mut Ctx,
+        ) -> Result<Self, minicbor::decode::Error>;
+    }
+
+    #[cfg(feature = "embedded-hal")]
+    pub trait WriteBits {
+        fn write_bits(&mut self, value: u8) -> Result<(), crate::PinError>;
+    }
+
+    pub trait TypeNames {
+        fn list_type_names(list: &mut core::fmt::DebugList<'_, '_>);
+
+        fn fill_type_names(names: &mut [&'static str]);
+    }
+}
+
+/// A generic chain element
+///
+/// This trait is sealed and only implemented by [`Chain`] and [`Link`], unless the `unsealed`
+/// feature is enabled. With the feature enabled, custom chain elements (e.g. links that count
+/// accesses to their objects) can implement this trait. Custom elements don't implement the other
+/// traits of this crate automatically, but can forward them to the elements they wrap.
+///
+/// Note that the `unsealed` feature is not covered by semver guarantees: new required items may
+/// be added to this trait in minor releases.
+#[allow(clippy::len_without_is_empty)]
+pub trait ChainElement: private::Sealed {
+    /// The type of the last appended object.
+    type Inner: ?Sized;
+
+    /// The chain this element was appended to, or `()` for a [`Chain`].
+    type Parent;
+
+    /// The number of objects linked to this chain element, as a type-level number.
+    ///
+    /// Together with [`ChainElement::Parent`], this allows writing recursive algorithms over
+    /// chains in terms of bounds, e.g. to require that a chain is longer than another one:
+    ///
+    /// ```rust
+    /// use object_chain::{cmp::Greater, Chain, ChainElement, Compare};
+    ///
+    /// fn longer<A, B>(_a: &A, _b: &B)
+    /// where
+    ///     A: ChainElement,
+    ///     B: ChainElement,
+    ///     A::Depth: Compare<B::Depth, Ordering = Greater>,
+    /// {
+    /// }
+    ///
+    /// longer(&Chain::new(1u8).append(2u8), &Chain::new(3u8));
+    /// ```
+    type Depth: Nat;
+
+    /// The number of objects linked to this chain element
+    const LEN: usize;
+
+    /// Append an object to the chain
+    #[inline]
+    fn append<T>(self, item: T) -> Link<T, Self>
+    where
+        Self: Sized,
+    {
+        Link {
+            object: item,
+            parent: self,
+        }
+    }
+
+    /// Append the default value of `T` to the chain.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use object_chain::{Chain, ChainElement};
+    ///
+    /// #[derive(Default)]
+    /// struct Uart {
+    ///     baud: u32,
+    /// }
+    ///
+    /// let configs = Chain::new(9_600u32).append_default::<Uart>().append_default::<bool>();
+    ///
+    /// assert_eq!(0, configs.parent.object.baud);
+    /// assert!(!configs.object);
+    /// ```
+    #[inline]
+    fn append_default<T: Default>(self) -> Link<T, Self>
+    where
+        Self: Sized,
+    {
+        self.append(T::default())
+    }
+
+    /// Append an object to the chain, requiring that its type may follow the type of the last
+    /// object.
+    ///
+    /// See [`After`] for more information.
+    #[inline]
+    fn append_after<T>(self, item: T) -> Link<T, Self>
+    where
+        Self: Sized,
+        T: After<Self::Inner>,
+    {
+        self.append(item)
+    }
+
+    /// Return the number of objects linked to this chain element
+    fn len(&self) -> usize;
+
+    /// Append every element of `tuple` to the chain, in order.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use object_chain::{Chain, ChainElement};
+    ///
+    /// let chain = Chain::new(1u8).append_tuple((2u16, "three"));
+    ///
+    /// assert_eq!(Chain::new(1u8).append(2u16).append("three"), chain);
+    /// ```
+    #[inline]
+    fn append_tuple<T>(self, tuple: T) -> <Self as AppendTuple<T>>::Output
+    where
+        Self: AppendTuple<T> + Sized,
+    {
+        self.append_elements(tuple)
+    }
+
+    /// Append every object of `other` to the chain, in the order they were appended to `other`.
+    ///
+    /// Chains can also be joined using the `+` operator.
+    #[inline]
+    fn concat<C>(self, other: C) -> <Self as Concat<C>>::Output
+    where
+        Self: Concat<C> + Sized,
+    {
+        self.concat_chain(other)
+    }
+
+    fn get(&self) -> &Self::Inner;
+
+    fn get_mut(&mut self) -> &mut Self::Inner;
+
+    fn pop(self) -> (Self::Inner, Self::Parent)
+    where
+        Self: Sized,
+        Self::Inner: Sized;
+
+    /// Replace the object stored in this chain element by `value`, returning the old object.
+    #[inline]
+    fn set(&mut self, value: Self::Inner) -> Self::Inner
+    where
+        Self::Inner: Sized,
+    {
+        core::mem::replace(self.get_mut(), value)
+    }
+
+    /// Replace the object stored in this chain element by its default value, returning the old
+    /// object.
+    #[inline]
+    fn take(&mut self) -> Self::Inner
+    where
+        Self::Inner: Default + Sized,
+    {
+        core::mem::take(self.get_mut())
+    }
+
+    /// Replace the object stored in this chain element by the value computed by `f` from the
+    /// current object, returning the old object.
+    #[inline]
+    fn replace(&mut self, f: impl FnOnce(&mut Self::Inner) -> Self::Inner) -> Self::Inner
+    where
+        Self::Inner: Sized,
+    {
+        let object = self.get_mut();
+        let value = f(object);
+        core::mem::replace(object, value)
+    }
+
+    /// Return a reference to the object of type `T`.
+    ///
+    /// The index type `I` is inferred by the compiler if the chain contains a single `T` object.
+    #[inline]
+    fn get_of<T, I>(&self) -> &T
+    where
+        Self: Selector<T, I> + Sized,
+    {
+        self.select()
+    }
+
+    /// Return a mutable reference to the object of type `T`.
+    ///
+    /// The index type `I` is inferred by the compiler if the chain contains a single `T` object.
+    #[inline]
+    fn get_of_mut<T, I>(&mut self) -> &mut T
+    where
+        Self: Selector<T, I> + Sized,
+    {
+        self.select_mut()
+    }
+
+    /// Call `f` with a mutable reference to the object of type `T`.
+    ///
+    /// The index type `I` is inferred by the compiler if the chain contains a single `T` object.
+    #[inline]
+    fn update_of<T, I>(&mut self, f: impl FnOnce(&mut T))
+    where
+        Self: Selector<T, I> + Sized,
+    {
+        f(self.select_mut())
+    }
+
+    /// Immutably borrow the object of type `T` from a chain of [`RefCell`]s.
+    ///
+    /// The index type `I` is inferred by the compiler if the chain contains a single
+    /// `RefCell<T>` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    ///
+    /// [`RefCell`]: core::cell::RefCell
+    #[inline]
+    fn borrow_of<T, I>(&self) -> core::cell::Ref<'_, T>
+    where
+        Self: Selector<core::cell::RefCell<T>, I> + Sized,
+    {
+        self.select().borrow()
+    }
+
+    /// Mutably borrow the object of type `T` from a chain of [`RefCell`]s.
+    ///
+    /// The index type `I` is inferred by the compiler if the chain contains a single
+    /// `RefCell<T>` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently borrowed.
+    ///
+    /// [`RefCell`]: core::cell::RefCell
+    #[inline]
+    fn borrow_mut_of<T, I>(&self) -> core::cell::RefMut<'_, T>
+    where
+        Self: Selector<core::cell::RefCell<T>, I> + Sized,
+    {
+        self.select().borrow_mut()
+    }
+
+    /// Mutably borrow the object of type `T` from a chain of [`Mutex`]es, created using
+    /// [`InMutex`].
+    ///
+    /// The index type `I` is inferred by the compiler if the chain contains a single `T` object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently borrowed.
+    ///
+    /// [`Mutex`]: critical_section::Mutex
+    #[cfg(feature = "critical-section")]
+    #[inline]
+    fn lock_of<'cs, T, I>(
+        &'cs self,
+        cs: critical_section::CriticalSection<'cs>,
+    ) -> core::cell::RefMut<'cs, T>
+    where
+        Self: Selector<critical_section::Mutex<core::cell::RefCell<T>>, I> + Sized,
+    {
+        self.select().borrow_ref_mut(cs)
+    }
+
+    /// Return the object of type `T`, or a `T` constructed by a [`Provide`] object from the
+    /// objects that were appended before it.
+    ///
+    /// The index type `I` is inferred by the compiler if there is a single way to resolve `T`.
+    /// See [`Resolve`] for more information.
+    #[inline]
+    fn resolve<T, I>(&self) -> Resolved<'_, T>
+    where
+        Self: Resolve<T, I> + Sized,
+    {
+        self.resolve_object()
+    }
+
+    /// Return a reference to the last appended object of type `T`, or `None` if the chain does
+    /// not contain a `T`.
+    ///
+    /// Unlike [`get_of`](ChainElement::get_of), the chain may contain more than one `T` object.
+    #[inline]
+    fn last_of<T: 'static>(&self) -> Option<&T>
+    where
+        Self: Occurrences + Sized,
+    {
+        self.find_type(core::any::TypeId::of::<T>())
+            .and_then(|object| object.downcast_ref())
+    }
+
+    /// Return a mutable reference to the last appended object of type `T`, or `None` if the chain
+    /// does not contain a `T`.
+    ///
+    /// Unlike [`get_of_mut`](ChainElement::get_of_mut), the chain may contain more than one `T`
+    /// object.
+    #[inline]
+    fn last_of_mut<T: 'static>(&mut self) -> Option<&mut T>
+    where
+        Self: Occurrences + Sized,
+    {
+        self.find_type_mut(core::any::TypeId::of::<T>())
+            .and_then(|object| object.downcast_mut())
+    }
+
+    /// Return a reference to the last appended object of type `T`, or
+    /// [`ChainError::TypeNotFound`] if the chain does not contain a `T`.
+    #[inline]
+    fn try_last_of<T: 'static>(&self) -> Result<&T, ChainError>
+    where
+        Self: Occurrences + Sized,
+    {
+        self.last_of().ok_or(ChainError::TypeNotFound)
+    }
+
+    /// Return a mutable reference to the last appended object of type `T`, or
+    /// [`ChainError::TypeNotFound`] if the chain does not contain a `T`.
+    #[inline]
+    fn try_last_of_mut<T: 'static>(&mut self) -> Result<&mut T, ChainError>
+    where
+        Self: Occurrences + Sized,
+    {
+        self.last_of_mut().ok_or(ChainError::TypeNotFound)
+    }
+
+    /// Return the number of objects of type `T` in the chain.
+    ///
+    /// The count is computed at runtime. Use [`count_of`] to count objects at compile time.
+    #[inline]
+    fn count_of<T: 'static>(&self) -> usize
+    where
+        Self: Occurrences + Sized,
+    {
+        Self::count_type(core::any::TypeId::of::<T>())
+    }
+
+    /// Return a reference to the object at position `N`, in the order the objects were appended.
+    ///
+    /// Positions are checked at compile time.
+    #[inline]
+    fn at<const N: usize>(&self) -> &<Self as At<<Const<N> as ToNat>::Nat>>::Output
+    where
+        Const<N>: ToNat,
+        Self: At<<Const<N> as ToNat>::Nat> + Sized,
+    {
+        self.at_position()
+    }
+
+    /// Return a mutable reference to the object at position `N`, in the order the objects were
+    /// appended.
+    ///
+    /// Positions are checked at compile time.
+    #[inline]
+    fn at_mut<const N: usize>(&mut self) -> &mut <Self as At<<Const<N> as ToNat>::Nat>>::Output
+    where
+        Const<N>: ToNat,
+        Self: At<<Const<N> as ToNat>::Nat> + Sized,
+    {
+        self.at_position_mut()
+    }
+
+    /// Return a reference to the object at the end of `Path`, which is a tuple of positions that
+    /// select objects in nested chains.
+    ///
+    /// See [`AtPath`] for more information.
+    #[inline]
+    fn at_path<Path>(&self) -> &<Self as AtPath<Path>>::Output
+    where
+        Self: AtPath<Path> + Sized,
+    {
+        self.get_path()
+    }
+
+    /// Return a mutable reference to the object at the end of `Path`, which is a tuple of
+    /// positions that select objects in nested chains.
+    ///
+    /// See [`AtPath`] for more information.
+    #[inline]
+    fn at_path_mut<Path>(&mut self) -> &mut <Self as AtPath<Path>>::Output
+    where
+        Self: AtPath<Path> + Sized,
+    {
+        self.get_path_mut()
+    }
+
+    /// Return a reference to the chain of the first `N` appended objects.
+    ///
+    /// The length is checked at compile time and must not be zero.
+    #[inline]
+    fn prefix<const N: usize>(&self) -> &<Self as ChainPrefix<<Const<N> as ToNat>::Nat>>::Prefix
+    where
+        Const<N>: ToNat,
+        Self: ChainPrefix<<Const<N> as ToNat>::Nat> + Sized,
+    {
+        self.borrow_prefix()
+    }
+
+    /// Return a mutable reference to the chain of the first `N` appended objects.
+    ///
+    /// The length is checked at compile time and must not be zero.
+    #[inline]
+    fn prefix_mut<const N: usize>(
+        &mut self,
+    ) -> &mut <Self as ChainPrefix<<Const<N> as ToNat>::Nat>>::Prefix
+    where
+        Const<N>: ToNat,
+        Self: ChainPrefix<<Const<N> as ToNat>::Nat> + Sized,
+    {
+        self.borrow_prefix_mut()
+    }
+
+    /// Return a chain of references to the objects at positions `M..N`, in the order the objects
+    /// were appended.
+    ///
+    /// The range is checked at compile time and must not be empty.
+    #[inline]
+    fn sub_chain<'a, const M: usize, const N: usize>(
+        &'a self,
+    ) -> <Self as SubChain<'a, <Const<M> as ToNat>::Nat, <Const<N> as ToNat>::Nat>>::Refs
+    where
+        Const<M>: ToNat,
+        Const<N>: ToNat,
+        Self: SubChain<'a, <Const<M> as ToNat>::Nat, <Const<N> as ToNat>::Nat> + Sized,
+    {
+        self.borrow_range()
+    }
+
+    /// Replace the object at position `N` by the result of `f`, which may have a different type.
+    ///
+    /// Positions are checked at compile time.
+    #[inline]
+    fn map_at<const N: usize, U>(
+        self,
+        f: impl FnOnce(<Self as At<<Const<N> as ToNat>::Nat>>::Output) -> U,
+    ) -> <Self as MapAt<<Const<N> as ToNat>::Nat>>::Mapped<U>
+    where
+        Const<N>: ToNat,
+        Self: MapAt<<Const<N> as ToNat>::Nat> + Sized,
+    {
+        self.map_at_position(f)
+    }
+
+    /// Return a chain of references to every object, in the order they were appended.
+    #[inline]
+    fn as_refs<'a>(&'a self) -> <Self as AsRefs<'a>>::Refs
+    where
+        Self: AsRefs<'a>,
+    {
+        self.borrow_each()
+    }
+
+    /// Return a chain of mutable references to every object, in the order they were appended.
+    #[inline]
+    fn as_mut_refs<'a>(&'a mut self) -> <Self as AsRefs<'a>>::RefsMut
+    where
+        Self: AsRefs<'a>,
+    {
+        self.borrow_each_mut()
+    }
+
+    /// Return the objects that match the predicate `P`, dropping the others.
+    ///
+    /// See [`Filter`] for more information.
+    #[inline]
+    fn filter<P>(self) -> <Self as Filter<P>>::Filtered
+    where
+        Self: Filter<P> + Sized,
+    {
+        self.filter_matching()
+    }
+
+    /// Split the chain into the objects that match the predicate `P` and the ones that don't.
+    ///
+    /// See [`Partition`] for more information.
+    #[inline]
+    fn partition<P>(self) -> (<Self as Partition<P>>::Kept, <Self as Partition<P>>::Rest)
+    where
+        Self: Partition<P> + Sized,
+    {
+        self.partition_matching()
+    }
+
+    /// Remove the object of type `T` from the chain, returning it along with the remaining
+    /// objects.
+    ///
+    /// See [`Pluck`] for more information.
+    #[inline]
+    fn pluck<T, I>(self) -> (T, <Self as Pluck<T, I>>::Remainder)
+    where
+        Self: Pluck<T, I> + Sized,
+    {
+        self.pluck_object()
+    }
+
+    /// Move the objects into a chain of type `Target`, which may list the object types in any
+    /// order, returning it along with the remaining objects.
+    ///
+    /// See [`Sculpt`] for more information.
+    #[inline]
+    fn sculpt<Target, I>(self) -> (Target, <Self as Sculpt<Target, I>>::Remainder)
+    where
+        Self: Sculpt<Target, I> + Sized,
+    {
+        self.sculpt_into()
+    }
+
+    /// Move the objects whose types are listed by the chain type `Subset` into a new chain,
+    /// returning it along with the remaining objects.
+    ///
+    /// See [`Sculpt`] for more information.
+    #[inline]
+    fn extract<Subset, I>(self) -> (Subset, <Self as Sculpt<Subset, I>>::Remainder)
+    where
+        Self: Sculpt<Subset, I> + Sized,
+    {
+        self.sculpt_into()
+    }
+
+    /// Return a chain of references to the objects whose types are listed by the chain type
+    /// `Subset`, in the order of `Subset`.
+    #[inline]
+    fn extract_ref<'a, Subset, I>(&'a self) -> <Subset as AsRefs<'a>>::Refs
+    where
+        Subset: AsRefs<'a>,
+        Self: AsRefs<'a>,
+        <Self as AsRefs<'a>>::Refs: Sculpt<<Subset as AsRefs<'a>>::Refs, I>,
+    {
+        self.borrow_each().sculpt_into().0
+    }
+
+    /// Return a chain of mutable references to the objects whose types are listed by the chain
+    /// type `Subset`, in the order of `Subset`.
+    #[inline]
+    fn extract_mut<'a, Subset, I>(&'a mut self) -> <Subset as AsRefs<'a>>::RefsMut
+    where
+        Subset: AsRefs<'a>,
+        Self: AsRefs<'a>,
+        <Self as AsRefs<'a>>::RefsMut: Sculpt<<Subset as AsRefs<'a>>::RefsMut, I>,
+    {
+        self.borrow_each_mut().sculpt_into().0
+    }
+
+    /// Merge the chain with `overrides`, taking the objects whose keys appear in both chains from
+    /// `overrides`.
+    ///
+    /// See [`Merge`] for more information.
+    #[inline]
+    fn merge<Rhs>(self, overrides: Rhs) -> <Self as Merge<Rhs>>::Merged
+    where
+        Self: Merge<Rhs> + Sized,
+    {
+        self.merge_with(overrides)
+    }
+
+    /// Measure every object using the metric `K` and reduce the values using the monoid `M`.
+    ///
+    /// See [`MeasureChain`] for more information.
+    #[inline]
+    fn aggregate<K, M>(&self) -> K::Value
+    where
+        Self: MeasureChain<K>,
+        K: Metric,
+        M: Monoid<K::Value>,
+    {
+        self.fold_measures(M::identity(), &mut M::combine)
+    }
+
+    /// Sort the objects of the chain by their sort keys.
+    ///
+    /// See [`Canonicalize`] for more information.
+    #[inline]
+    fn canonicalize(self) -> <Self as Canonicalize>::Canonical
+    where
+        Self: Canonicalize + Sized,
+    {
+        self.into_canonical()
+    }
+
+    /// Combine the objects of this chain with the objects of `other` at the same positions, using
+    /// `combiner`.
+    ///
+    /// The chains must have the same length. See [`ZipWith`] for more information.
+    #[inline]
+    fn zip_with<O, F>(self, other: O, mut combiner: F) -> <Self as ZipWith<O, F>>::Zipped
+    where
+        Self: ZipWith<O, F> + Sized,
+    {
+        self.zip_with_combiner(other, &mut combiner)
+    }
+
+    /// Pair the objects of this chain with the objects of `other` at the same positions.
+    ///
+    /// The chains must have the same length.
+    #[inline]
+    fn zip<O>(self, other: O) -> <Self as ZipWith<O, Pair>>::Zipped
+    where
+        Self: ZipWith<O, Pair> + Sized,
+    {
+        self.zip_with_combiner(other, &mut Pair)
+    }
+
+    /// Split a chain of pairs into a chain of the first elements and a chain of the second
+    /// elements.
+    #[inline]
+    fn unzip(self) -> (<Self as Unzip>::Left, <Self as Unzip>::Right)
+    where
+        Self: Unzip + Sized,
+    {
+        self.unzip_pairs()
+    }
+
+    /// Call every function of this chain with the object of `args` at the same position.
+    ///
+    /// The chains must have the same length. Returns the chain of the results, in the order the
+    /// functions were appended.
+    #[inline]
+    fn apply<A>(self, args: A) -> <Self as ZipWith<A, Call>>::Zipped
+    where
+        Self: ZipWith<A, Call> + Sized,
+    {
+        self.zip_with_combiner(args, &mut Call)
+    }
+
+    /// Transform every object of the chain using `mapper`.
+    ///
+    /// See [`MapChain`] for more information.
+    #[inline]
+    fn map<M>(self, mut mapper: M) -> <Self as MapChain<M>>::Mapped
+    where
+        Self: MapChain<M> + Sized,
+    {
+        self.map_with(&mut mapper)
+    }
 
-/// A generic chain element
-#[allow(clippy::len_without_is_empty)]
-pub trait ChainElement: private::Sealed {
-    type Inner;
-    type Parent;
+    /// Transform a reference to every object of the chain using `mapper`, without consuming the
+    /// chain.
+    ///
+    /// See [`MapChain`] for more information.
+    #[inline]
+    fn map_ref<'a, M>(
+        &'a self,
+        mut mapper: M,
+    ) -> <<Self as AsRefs<'a>>::Refs as MapChain<M>>::Mapped
+    where
+        Self: AsRefs<'a>,
+        <Self as AsRefs<'a>>::Refs: MapChain<M>,
+    {
+        self.borrow_each().map_with(&mut mapper)
+    }
 
-    /// Append an object to the chain
+    /// Transform every object of the chain using `scanner`, which carries a state from one object
+    /// to the next, starting with `init`.
+    ///
+    /// This method works like [`Iterator::scan`], except that every object is transformed.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use object_chain::{Chain, ChainElement};
+    ///
+    /// let budgets = Chain::new(30u32).append(50).append(40);
+    ///
+    /// let within_budget = budgets.scan(100, |remaining: &mut u32, budget: u32| {
+    ///     let granted = budget.min(*remaining);
+    ///     *remaining -= granted;
+    ///     granted
+    /// });
+    ///
+    /// assert_eq!(Chain::new(30).append(50).append(20), within_budget);
+    /// ```
     #[inline]
-    fn append<T>(self, item: T) -> Link<T, Self>
+    fn scan<S, F>(self, init: S, scanner: F) -> <Self as MapChain<Scan<S, F>>>::Mapped
+    where
+        Self: MapChain<Scan<S, F>> + Sized,
+    {
+        self.map_with(&mut Scan::new(init, scanner))
+    }
+
+    /// Transform every object of the chain using `mapper`, stopping at the first error.
+    ///
+    /// See [`TryMapChain`] for more information.
+    #[inline]
+    fn try_map<M>(
+        self,
+        mut mapper: M,
+    ) -> Result<<Self as TryMapChain<M>>::Mapped, <Self as TryMapChain<M>>::Error>
+    where
+        Self: TryMapChain<M> + Sized,
+    {
+        self.try_map_with(&mut mapper)
+    }
+
+    /// Wrap every object of the chain using `W`, e.g. into a [`RefCell`] using [`InRefCell`].
+    ///
+    /// [`RefCell`]: core::cell::RefCell
+    #[inline]
+    fn wrap_each<W: Wrapper>(self) -> <Self as WrapEach>::Wrapped<W>
+    where
+        Self: WrapEach + Sized,
+    {
+        self.wrap_each_with::<W>()
+    }
+
+    /// Return an adapter that prints the type of every object in the chain using [`Debug`].
+    ///
+    /// The objects don't need to implement `Debug`.
+    ///
+    /// [`Debug`]: core::fmt::Debug
+    #[inline]
+    fn debug_types(&self) -> DebugTypes<Self>
     where
         Self: Sized,
     {
-        Link {
-            object: item,
-            parent: self,
+        DebugTypes::new()
+    }
+
+    /// Consume the chain and return an array of its objects converted into `T`, in the order they
+    /// were appended. Every object must implement `Into<T>`.
+    ///
+    /// The length of the array must be equal to the length of the chain, which is checked at
+    /// compile time.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use object_chain::{Chain, ChainElement};
+    ///
+    /// struct Channel(u8);
+    ///
+    /// impl From<Channel> for u32 {
+    ///     fn from(channel: Channel) -> u32 {
+    ///         1 << channel.0
+    ///     }
+    /// }
+    ///
+    /// let channels: [u32; 3] = Chain::new(Channel(2)).append(8u8).append(3u16).collect_array();
+    ///
+    /// assert_eq!([4, 8, 3], channels);
+    /// ```
+    #[inline]
+    fn collect_array<T, const N: usize>(self) -> [T; N]
+    where
+        Self: private::FillElements<T> + Sized,
+    {
+        const { assert!(N == Self::LEN, "N must be equal to the length of the chain") };
+
+        let mut slots = [const { None }; N];
+        self.fill_elements(&mut slots);
+        slots.map(|slot| match slot {
+            Some(element) => element,
+            None => unreachable!(),
+        })
+    }
+
+    /// Consume the chain and return a [`heapless::Vec`] of its objects converted into `T`, in the
+    /// order they were appended. Every object must implement `Into<T>`.
+    ///
+    /// The chain must not be longer than the capacity of the vector, which is checked at compile
+    /// time.
+    #[cfg(feature = "heapless")]
+    #[inline]
+    fn collect_vec<T, const CAP: usize>(self) -> heapless::Vec<T, CAP>
+    where
+        Self: private::FillElements<T> + Sized,
+    {
+        const { assert!(Self::LEN <= CAP, "the chain doesn't fit into the vector") };
+
+        let mut slots = [const { None }; CAP];
+        self.fill_elements(&mut slots);
+
+        let mut vec = heapless::Vec::new();
+        for element in IntoIterator::into_iter(slots).flatten() {
+            if vec.push(element).is_err() {
+                unreachable!();
+            }
         }
+        vec
     }
 
-    /// Return the number of objects linked to this chain element
-    fn len(&self) -> usize;
+    /// Consume the chain and return an iterator that yields every object as a variant of the enum
+    /// `E`, in the order they were appended.
+    ///
+    /// Use [`chain_enum!`] to declare such an enum.
+    #[inline]
+    fn into_elements<E>(self) -> E::Elements
+    where
+        E: ChainEnum<Chain = Self>,
+        Self: Sized,
+    {
+        E::split(self)
+    }
 
-    fn get(&self) -> &Self::Inner;
+    /// Drop the objects of the chain, starting with the first appended one.
+    ///
+    /// This is the order in which chains drop their objects by default. Calling this method
+    /// documents that the code relies on it.
+    #[inline]
+    fn drop_front_to_back(self)
+    where
+        Self: Sized,
+    {
+        drop(self);
+    }
 
-    fn get_mut(&mut self) -> &mut Self::Inner;
+    /// Drop the objects of the chain, starting with the last appended one.
+    ///
+    /// See [`ReverseDrop`] to drop a chain in this order when it goes out of scope.
+    #[inline]
+    fn drop_back_to_front(self)
+    where
+        Self: private::DropInReverse + Sized,
+    {
+        drop(ReverseDrop::new(self));
+    }
+
+    /// Start a transaction that restores the chain if it is not committed.
+    ///
+    /// See [`Transaction`] for more information.
+    #[inline]
+    fn transaction(&mut self) -> Transaction<'_, Self>
+    where
+        Self: Clone + Sized,
+    {
+        Transaction::new(self)
+    }
+
+    /// Return a view of the chain that accesses every object as the trait object type `D`.
+    #[inline]
+    fn as_dyn<D>(&self) -> DynView<'_, Self, D>
+    where
+        Self: DynChain<D> + Sized,
+        D: ?Sized,
+    {
+        DynView::new(self)
+    }
+
+    /// Return a view of the chain that mutably accesses every object as the trait object type `D`.
+    #[inline]
+    fn as_dyn_mut<D>(&mut self) -> DynViewMut<'_, Self, D>
+    where
+        Self: DynChain<D> + Sized,
+        D: ?Sized,
+    {
+        DynViewMut::new(self)
+    }
 
-    fn pop(self) -> (Self::Inner, Self::Parent);
+    /// Consume the chain and return an iterator that yields every object as a boxed trait object,
+    /// in the order they were appended.
+    #[cfg(feature = "alloc")]
+    fn into_boxed_iter<D>(self) -> alloc::vec::IntoIter<alloc::boxed::Box<D>>
+    where
+        Self: BoxedChain<D> + Sized,
+        D: ?Sized,
+    {
+        let mut vec = alloc::vec::Vec::with_capacity(self.len());
+        self.push_boxed(&mut vec);
+        vec.into_iter()
+    }
 }
 
 /// This piece of the chain contains some object
-#[derive(Clone, Copy)]
-pub struct Link<V, C>
+///
+/// The object may be a dynamically sized type, e.g. a slice or a trait object, if this is the last
+/// link of the chain. Such chains can be created by unsizing a reference to a chain whose last
+/// object has a sized type, e.g. `&Link<[u8; 4], C>` to `&Link<[u8], C>`.
+///
+/// Chains implement [`Hash`](core::hash::Hash) if their objects do, hashing the objects in the
+/// order they were appended. `hash32` 0.3 hashers hash through `core::hash::Hash`, so chains can
+/// also be used as keys of `heapless` maps.
+#[derive(Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Link<V: ?Sized, C>
 where
     C: ChainElement,
 {
@@ -56,16 +1279,86 @@ where
     pub object: V,
 }
 
-impl<V, VC> ChainElement for Link<V, VC>
+impl<V, C: ChainElement> Link<V, C> {
+    /// Consumes the link and returns the object and the rest of the chain.
+    #[inline]
+    pub fn into_parts(self) -> (V, C) {
+        (self.object, self.parent)
+    }
+
+    /// Consumes the link and returns the rest of the chain, dropping the object.
+    #[inline]
+    pub fn into_parent(self) -> C {
+        self.parent
+    }
+
+    /// Consumes the link and returns the object, dropping the rest of the chain.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.object
+    }
+
+    /// Transforms the object of the link, keeping the rest of the chain.
+    #[inline]
+    pub fn map_head<U, F: FnOnce(V) -> U>(self, f: F) -> Link<U, C> {
+        Link {
+            parent: self.parent,
+            object: f(self.object),
+        }
+    }
+
+    /// Transforms the rest of the chain, keeping the object of the link.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use object_chain::{Chain, ChainElement};
+    ///
+    /// let chain = Chain::new(1u8).append(2u16).append(3u32);
+    /// let mapped = chain.map_parent(|parent| parent.map_head(|object| object * 10));
+    ///
+    /// assert_eq!(Chain::new(1u8).append(20u16).append(3u32), mapped);
+    /// ```
+    #[inline]
+    pub fn map_parent<P: ChainElement, F: FnOnce(C) -> P>(self, f: F) -> Link<V, P> {
+        Link {
+            parent: f(self.parent),
+            object: self.object,
+        }
+    }
+}
+
+// `clone_from` is forwarded to every object, so refreshing a chain from a template can reuse the
+// resources, e.g. buffers, the objects already own.
+impl<V: Clone, C: ChainElement + Clone> Clone for Link<V, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            parent: self.parent.clone(),
+            object: self.object.clone(),
+        }
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.parent.clone_from(&source.parent);
+        self.object.clone_from(&source.object);
+    }
+}
+
+impl<V: ?Sized, VC> ChainElement for Link<V, VC>
 where
     VC: ChainElement,
 {
     type Inner = V;
     type Parent = VC;
+    type Depth = Succ<VC::Depth>;
+
+    const LEN: usize = VC::LEN + 1;
 
     #[inline]
     fn len(&self) -> usize {
-        self.parent.len() + 1
+        Self::LEN
     }
 
     fn get(&self) -> &Self::Inner {
@@ -76,14 +1369,21 @@ where
         &mut self.object
     }
 
-    fn pop(self) -> (Self::Inner, Self::Parent) {
+    fn pop(self) -> (Self::Inner, Self::Parent)
+    where
+        Self::Inner: Sized,
+    {
         (self.object, self.parent)
     }
 }
 
 /// This piece marks the end of a chain.
-#[derive(Clone, Copy)]
-pub struct Chain<V> {
+///
+/// Just like with [`Link`], the object may be a dynamically sized type, and the chain can be
+/// hashed with `hash32` 0.3 hashers.
+#[derive(Copy, PartialEq, Eq, Hash)]
+#[repr(C)]
+pub struct Chain<V: ?Sized> {
     /// The wrapped object.
     pub object: V,
 }
@@ -93,15 +1393,44 @@ impl<V> Chain<V> {
     pub const fn new(object: V) -> Self {
         Self { object }
     }
+
+    /// Consumes the chain element and returns the wrapped object.
+    #[inline]
+    pub fn into_inner(self) -> V {
+        self.object
+    }
+
+    /// Transforms the wrapped object.
+    #[inline]
+    pub fn map_head<U, F: FnOnce(V) -> U>(self, f: F) -> Chain<U> {
+        Chain::new(f(self.object))
+    }
+}
+
+impl<V: Clone> Clone for Chain<V> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            object: self.object.clone(),
+        }
+    }
+
+    #[inline]
+    fn clone_from(&mut self, source: &Self) {
+        self.object.clone_from(&source.object);
+    }
 }
 
-impl<V> ChainElement for Chain<V> {
+impl<V: ?Sized> ChainElement for Chain<V> {
     type Inner = V;
     type Parent = ();
+    type Depth = Succ<Zero>;
+
+    const LEN: usize = 1;
 
     #[inline]
     fn len(&self) -> usize {
-        1
+        Self::LEN
     }
 
     fn get(&self) -> &Self::Inner {
@@ -112,7 +1441,10 @@ impl<V> ChainElement for Chain<V> {
         &mut self.object
     }
 
-    fn pop(self) -> (Self::Inner, Self::Parent) {
+    fn pop(self) -> (Self::Inner, Self::Parent)
+    where
+        Self::Inner: Sized,
+    {
         (self.object, ())
     }
 }
@@ -147,6 +1479,108 @@ macro_rules! reverse {
     };
 }
 
+/// Repeat a type to generate a homogeneous object chain
+#[doc(hidden)]
+#[macro_export(local_inner_macros)]
+macro_rules! chain_repeat {
+    ($x:ty; 1) => {
+        Chain<$x>
+    };
+    ($x:ty; 2) => {
+        Link<$x, chain_repeat! { $x; 1 }>
+    };
+    ($x:ty; 3) => {
+        Link<$x, chain_repeat! { $x; 2 }>
+    };
+    ($x:ty; 4) => {
+        Link<$x, chain_repeat! { $x; 3 }>
+    };
+    ($x:ty; 5) => {
+        Link<$x, chain_repeat! { $x; 4 }>
+    };
+    ($x:ty; 6) => {
+        Link<$x, chain_repeat! { $x; 5 }>
+    };
+    ($x:ty; 7) => {
+        Link<$x, chain_repeat! { $x; 6 }>
+    };
+    ($x:ty; 8) => {
+        Link<$x, chain_repeat! { $x; 7 }>
+    };
+    ($x:ty; 9) => {
+        Link<$x, chain_repeat! { $x; 8 }>
+    };
+    ($x:ty; 10) => {
+        Link<$x, chain_repeat! { $x; 9 }>
+    };
+    ($x:ty; 11) => {
+        Link<$x, chain_repeat! { $x; 10 }>
+    };
+    ($x:ty; 12) => {
+        Link<$x, chain_repeat! { $x; 11 }>
+    };
+    ($x:ty; 13) => {
+        Link<$x, chain_repeat! { $x; 12 }>
+    };
+    ($x:ty; 14) => {
+        Link<$x, chain_repeat! { $x; 13 }>
+    };
+    ($x:ty; 15) => {
+        Link<$x, chain_repeat! { $x; 14 }>
+    };
+    ($x:ty; 16) => {
+        Link<$x, chain_repeat! { $x; 15 }>
+    };
+    ($x:ty; 17) => {
+        Link<$x, chain_repeat! { $x; 16 }>
+    };
+    ($x:ty; 18) => {
+        Link<$x, chain_repeat! { $x; 17 }>
+    };
+    ($x:ty; 19) => {
+        Link<$x, chain_repeat! { $x; 18 }>
+    };
+    ($x:ty; 20) => {
+        Link<$x, chain_repeat! { $x; 19 }>
+    };
+    ($x:ty; 21) => {
+        Link<$x, chain_repeat! { $x; 20 }>
+    };
+    ($x:ty; 22) => {
+        Link<$x, chain_repeat! { $x; 21 }>
+    };
+    ($x:ty; 23) => {
+        Link<$x, chain_repeat! { $x; 22 }>
+    };
+    ($x:ty; 24) => {
+        Link<$x, chain_repeat! { $x; 23 }>
+    };
+    ($x:ty; 25) => {
+        Link<$x, chain_repeat! { $x; 24 }>
+    };
+    ($x:ty; 26) => {
+        Link<$x, chain_repeat! { $x; 25 }>
+    };
+    ($x:ty; 27) => {
+        Link<$x, chain_repeat! { $x; 26 }>
+    };
+    ($x:ty; 28) => {
+        Link<$x, chain_repeat! { $x; 27 }>
+    };
+    ($x:ty; 29) => {
+        Link<$x, chain_repeat! { $x; 28 }>
+    };
+    ($x:ty; 30) => {
+        Link<$x, chain_repeat! { $x; 29 }>
+    };
+    ($x:ty; 31) => {
+        Link<$x, chain_repeat! { $x; 30 }>
+    };
+    ($x:ty; 32) => {
+        Link<$x, chain_repeat! { $x; 31 }>
+    };
+}
+
 /// Creates an object chain from the argument types.
 ///
 /// Using this macro is completely optional but it reduces the boilerplate required to describe
@@ -176,8 +1610,21 @@ macro_rules! reverse {
 ///
 /// Note also how the order of types follows the type of objects in the chain instead of being
 /// reversed.
+///
+/// A chain of `N` objects of the same type can be described using the `chain![T; N]` form, where
+/// `N` is an integer literal between 1 and 32:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, Link, chain};
+/// type Bytes = chain![u8; 3];
+///
+/// let bytes: Bytes = Chain::new(1).append(2).append(3);
+/// ```
 #[macro_export(local_inner_macros)]
 macro_rules! chain {
+    [$x:ty; $n:tt] => {
+        chain_repeat! { $x; $n }
+    };
     [$($types:ty),+] => {
         reverse!{ [ $($types),+ ] }
     };
@@ -227,6 +1674,170 @@ mod test {
     pub fn test_count() {
         assert_eq!(1, Chain::new(0).len());
         assert_eq!(3, Chain::new(0u8).append(1u16).append(2u32).len());
+
+        assert_eq!(1, <chain![u8]>::LEN);
+        assert_eq!(3, <chain![u8, u16, u32]>::LEN);
+    }
+
+    #[test]
+    pub fn test_append_default() {
+        let chain: chain![u8, u16, Option<char>] =
+            Chain::new(1u8).append_default().append_default();
+
+        assert_eq!(Chain::new(1u8).append(0u16).append(None), chain);
+    }
+
+    #[test]
+    pub fn test_set_take_replace() {
+        let mut chain = Chain::new(1u8).append(2u16);
+
+        assert_eq!(2, chain.set(3));
+        assert_eq!(3, chain.take());
+        assert_eq!(0, chain.object);
+
+        assert_eq!(1, chain.parent.replace(|object| *object + 5));
+        assert_eq!(6, chain.parent.object);
+    }
+
+    #[test]
+    pub fn test_consuming_accessors() {
+        let chain = Chain::new(1u8).append(2u16).append(3u32);
+
+        let (object, parent) = chain.into_parts();
+        assert_eq!(3, object);
+        assert_eq!(1, parent.into_parent().into_inner());
+
+        assert_eq!(5, Chain::new(5u8).into_inner());
+    }
+
+    #[test]
+    pub fn test_map_head_and_parent() {
+        let chain = Chain::new(1u8).append(2u16);
+
+        let chain = chain.map_head(|object| object > 1);
+        assert_eq!(Chain::new(1u8).append(true), chain);
+
+        let chain = chain.map_parent(|parent| parent.map_head(|object| object as u32 + 4));
+        assert_eq!(Chain::new(5u32).append(true), chain);
+
+        let chain = chain.map_parent(|parent| parent.append('a'));
+        assert_eq!(Chain::new(5u32).append('a').append(true), chain);
+    }
+
+    #[test]
+    pub fn test_clone_from() {
+        #[derive(Debug, PartialEq)]
+        struct Buffer {
+            data: u32,
+            reused: bool,
+        }
+
+        impl Clone for Buffer {
+            fn clone(&self) -> Self {
+                Self {
+                    data: self.data,
+                    reused: false,
+                }
+            }
+
+            fn clone_from(&mut self, source: &Self) {
+                self.data = source.data;
+                self.reused = true;
+            }
+        }
+
+        let template = Chain::new(Buffer {
+            data: 1,
+            reused: false,
+        })
+        .append(Buffer {
+            data: 2,
+            reused: false,
+        });
+
+        let mut chain = template.clone();
+        assert!(!chain.object.reused);
+
+        chain.parent.object.data = 3;
+        chain.clone_from(&template);
+
+        assert_eq!(1, chain.parent.object.data);
+        assert!(chain.parent.object.reused);
+        assert_eq!(2, chain.object.data);
+        assert!(chain.object.reused);
+    }
+
+    #[test]
+    pub fn test_unsized_last_object() {
+        trait Stage {
+            fn run(&self) -> u32;
+        }
+
+        impl Stage for u32 {
+            fn run(&self) -> u32 {
+                *self
+            }
+        }
+
+        let mut chain = Chain::new(1u8).append([2u8, 3, 4]);
+        let tail: &mut Link<[u8], Chain<u8>> = &mut chain;
+
+        tail.get_mut()[0] = 5;
+        assert_eq!(2, tail.len());
+        assert_eq!(&[5, 3, 4], tail.get());
+
+        let stages: &Link<dyn Stage, Chain<u8>> = &Chain::new(1u8).append(6u32);
+        assert_eq!(6, stages.object.run());
+    }
+
+    #[test]
+    #[cfg(feature = "unsealed")]
+    pub fn test_custom_element() {
+        use core::cell::Cell;
+
+        struct Counting<C> {
+            inner: C,
+            accesses: Cell<u32>,
+        }
+
+        impl<C: ChainElement> ChainElement for Counting<C> {
+            type Inner = C::Inner;
+            type Parent = C::Parent;
+            type Depth = C::Depth;
+
+            const LEN: usize = C::LEN;
+
+            fn len(&self) -> usize {
+                self.inner.len()
+            }
+
+            fn get(&self) -> &Self::Inner {
+                self.accesses.set(self.accesses.get() + 1);
+                self.inner.get()
+            }
+
+            fn get_mut(&mut self) -> &mut Self::Inner {
+                self.accesses.set(self.accesses.get() + 1);
+                self.inner.get_mut()
+            }
+
+            fn pop(self) -> (Self::Inner, Self::Parent)
+            where
+                Self::Inner: Sized,
+            {
+                self.inner.pop()
+            }
+        }
+
+        let mut chain = Counting {
+            inner: Chain::new(1u8).append(2u16),
+            accesses: Cell::new(0),
+        };
+
+        assert_eq!(2, *chain.get());
+        assert_eq!(2, chain.set(3));
+        assert_eq!(2, chain.accesses.get());
+        assert_eq!(2, chain.len());
     }
 
     #[test]
@@ -280,4 +1891,45 @@ mod test {
 
         do_test(&Chain::new(1u8).append(2u16).append(3u16));
     }
+
+    #[test]
+    pub fn test_hash() {
+        use core::hash::Hash;
+        use hash32::{FnvHasher, Hasher};
+
+        let mut hasher = FnvHasher::default();
+        Chain::new(1u8).append(2u32).append('c').hash(&mut hasher);
+
+        let mut expected = FnvHasher::default();
+        1u8.hash(&mut expected);
+        2u32.hash(&mut expected);
+        'c'.hash(&mut expected);
+
+        assert_eq!(expected.finish32(), hasher.finish32());
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    pub fn test_index_map_key() {
+        use heapless::index_map::FnvIndexMap;
+
+        type Key = Link<char, Link<u32, Chain<u8>>>;
+
+        let mut map = FnvIndexMap::<Key, &str, 4>::new();
+
+        map.insert(Chain::new(1u8).append(2).append('a'), "first")
+            .unwrap();
+        map.insert(Chain::new(1u8).append(3).append('a'), "second")
+            .unwrap();
+
+        assert_eq!(
+            Some(&"first"),
+            map.get(&Chain::new(1u8).append(2).append('a'))
+        );
+        assert_eq!(
+            Some(&"second"),
+            map.get(&Chain::new(1u8).append(3).append('a'))
+        );
+        assert_eq!(None, map.get(&Chain::new(2u8).append(2).append('a')));
+    }
 }
@@ -0,0 +1,167 @@
+//! Acquiring the locks of a chain of resources in a fixed order.
+
+use core::ops::{Deref, DerefMut};
+
+use crate::{private, Chain, ChainElement, Link, ReverseDrop};
+
+/// A resource that can be locked, e.g. a mutex.
+pub trait Lockable {
+    /// The guard that keeps the resource locked until it is dropped.
+    type Guard<'a>
+    where
+        Self: 'a;
+
+    /// Locks the resource, waiting until it is available.
+    fn lock(&self) -> Self::Guard<'_>;
+}
+
+/// A chain of [`Lockable`] resources.
+///
+/// Locking every resource of a chain always happens in the order the resources were appended.
+/// Code that only acquires multiple locks using [`LockChain::lock_all`] on chains that agree on
+/// the order of the resources can't deadlock.
+///
+/// # Example:
+///
+/// ```rust
+/// use core::cell::{RefCell, RefMut};
+/// use object_chain::{Chain, ChainElement, LockChain, Lockable};
+///
+/// struct Resource(RefCell<u32>);
+///
+/// impl Lockable for Resource {
+///     type Guard<'a> = RefMut<'a, u32>;
+///
+///     fn lock(&self) -> RefMut<'_, u32> {
+///         self.0.borrow_mut()
+///     }
+/// }
+///
+/// let resources = Chain::new(Resource(RefCell::new(1))).append(Resource(RefCell::new(2)));
+///
+/// let mut guards = resources.lock_all();
+/// *guards.object += *guards.parent.object;
+/// drop(guards);
+///
+/// assert_eq!(3, *resources.object.0.borrow());
+/// ```
+pub trait LockChain: ChainElement {
+    /// The chain of the guards of the resources.
+    type Guards<'a>: ChainElement + private::DropInReverse
+    where
+        Self: 'a;
+
+    /// Locks every resource, in the order they were appended.
+    ///
+    /// The resources are unlocked in the reverse order when the returned guard is dropped.
+    #[inline]
+    fn lock_all(&self) -> GuardChain<Self::Guards<'_>> {
+        GuardChain {
+            guards: ReverseDrop::new(self.lock_each()),
+        }
+    }
+
+    /// Locks every resource, in the order they were appended, and returns their guards.
+    #[doc(hidden)]
+    fn lock_each(&self) -> Self::Guards<'_>;
+}
+
+impl<V: Lockable> LockChain for Chain<V> {
+    type Guards<'a>
+        = Chain<V::Guard<'a>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn lock_each(&self) -> Self::Guards<'_> {
+        Chain::new(self.object.lock())
+    }
+}
+
+impl<V: Lockable, C: LockChain> LockChain for Link<V, C> {
+    type Guards<'a>
+        = Link<V::Guard<'a>, C::Guards<'a>>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn lock_each(&self) -> Self::Guards<'_> {
+        self.parent.lock_each().append(self.object.lock())
+    }
+}
+
+/// The guards of the resources of a [`LockChain`], returned by [`LockChain::lock_all`].
+///
+/// The guard dereferences to the chain of the guards of the individual resources. Dropping it
+/// unlocks the resources in the reverse order they were locked.
+pub struct GuardChain<G: private::DropInReverse> {
+    guards: ReverseDrop<G>,
+}
+
+impl<G: private::DropInReverse> Deref for GuardChain<G> {
+    type Target = G;
+
+    #[inline]
+    fn deref(&self) -> &G {
+        &self.guards
+    }
+}
+
+impl<G: private::DropInReverse> DerefMut for GuardChain<G> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut G {
+        &mut self.guards
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::cell::Cell;
+
+    struct Resource<'a> {
+        id: u32,
+        log: &'a Cell<u32>,
+    }
+
+    struct Guard<'a> {
+        id: u32,
+        log: &'a Cell<u32>,
+    }
+
+    impl Drop for Guard<'_> {
+        fn drop(&mut self) {
+            self.log.set(self.log.get() * 10 + self.id);
+        }
+    }
+
+    impl Lockable for Resource<'_> {
+        type Guard<'a>
+            = Guard<'a>
+        where
+            Self: 'a;
+
+        fn lock(&self) -> Guard<'_> {
+            self.log.set(self.log.get() * 10 + self.id);
+            Guard {
+                id: self.id,
+                log: self.log,
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_lock_order() {
+        let log = Cell::new(0);
+        let resources = Chain::new(Resource { id: 1, log: &log })
+            .append(Resource { id: 2, log: &log })
+            .append(Resource { id: 3, log: &log });
+
+        let guards = resources.lock_all();
+        assert_eq!(123, log.get());
+        assert_eq!(2, guards.parent.object.id);
+
+        drop(guards);
+        assert_eq!(123_321, log.get());
+    }
+}
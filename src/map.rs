@@ -0,0 +1,305 @@
+//! Transforming every object of a chain into a different type.
+
+use crate::{Chain, ChainElement, Link};
+
+/// Transforms an object of type `T`.
+///
+/// The objects of a chain usually have different types, so a mapper implements this trait once
+/// for every type it can transform. This trait is also implemented for closures, which can be used
+/// if every object has the same type.
+pub trait Mapper<T> {
+    /// The type of the transformed object.
+    type Output;
+
+    /// Transforms `object`.
+    fn map(&mut self, object: T) -> Self::Output;
+}
+
+impl<T, O, F: FnMut(T) -> O> Mapper<T> for F {
+    type Output = O;
+
+    #[inline]
+    fn map(&mut self, object: T) -> O {
+        self(object)
+    }
+}
+
+/// Implemented by chains whose every object can be transformed by the mapper `M`.
+///
+/// Use [`ChainElement::map_ref`] to transform references to the objects without consuming the
+/// chain.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, Mapper};
+///
+/// struct Thermometer {
+///     celsius: i16,
+/// }
+///
+/// struct Battery {
+///     millivolts: u16,
+/// }
+///
+/// struct Report;
+///
+/// impl Mapper<&Thermometer> for Report {
+///     type Output = i16;
+///
+///     fn map(&mut self, sensor: &Thermometer) -> i16 {
+///         sensor.celsius
+///     }
+/// }
+///
+/// impl Mapper<&Battery> for Report {
+///     type Output = bool;
+///
+///     fn map(&mut self, battery: &Battery) -> bool {
+///         battery.millivolts < 3_300
+///     }
+/// }
+///
+/// let drivers = Chain::new(Thermometer { celsius: 21 }).append(Battery { millivolts: 3_100 });
+///
+/// let report = drivers.map_ref(Report);
+///
+/// assert_eq!(Chain::new(21).append(true), report);
+/// assert_eq!(21, drivers.parent.object.celsius);
+/// ```
+pub trait MapChain<M>: ChainElement {
+    /// The chain of transformed objects.
+    type Mapped: ChainElement;
+
+    /// Transforms every object, in the order they were appended.
+    fn map_with(self, mapper: &mut M) -> Self::Mapped;
+}
+
+impl<V, M: Mapper<V>> MapChain<M> for Chain<V> {
+    type Mapped = Chain<M::Output>;
+
+    #[inline]
+    fn map_with(self, mapper: &mut M) -> Self::Mapped {
+        Chain::new(mapper.map(self.object))
+    }
+}
+
+impl<V, C: MapChain<M>, M: Mapper<V>> MapChain<M> for Link<V, C> {
+    type Mapped = Link<M::Output, C::Mapped>;
+
+    #[inline]
+    fn map_with(self, mapper: &mut M) -> Self::Mapped {
+        let parent = self.parent.map_with(mapper);
+        Link {
+            parent,
+            object: mapper.map(self.object),
+        }
+    }
+}
+
+/// Transforms an object of type `T`, using and updating a state of type `S`.
+///
+/// This trait is also implemented for closures, which can be used if every object has the same
+/// type.
+pub trait Scanner<S, T> {
+    /// The type of the transformed object.
+    type Output;
+
+    /// Transforms `object`.
+    fn scan(&mut self, state: &mut S, object: T) -> Self::Output;
+}
+
+impl<S, T, O, F: FnMut(&mut S, T) -> O> Scanner<S, T> for F {
+    type Output = O;
+
+    #[inline]
+    fn scan(&mut self, state: &mut S, object: T) -> O {
+        self(state, object)
+    }
+}
+
+/// Adapts a [`Scanner`] and its state into a [`Mapper`]. Used by [`ChainElement::scan`].
+pub struct Scan<S, F> {
+    state: S,
+    scanner: F,
+}
+
+impl<S, F> Scan<S, F> {
+    #[inline]
+    pub(crate) fn new(state: S, scanner: F) -> Self {
+        Self { state, scanner }
+    }
+}
+
+impl<S, T, F: Scanner<S, T>> Mapper<T> for Scan<S, F> {
+    type Output = F::Output;
+
+    #[inline]
+    fn map(&mut self, object: T) -> F::Output {
+        self.scanner.scan(&mut self.state, object)
+    }
+}
+
+/// Transforms an object of type `T`, or fails with an error.
+///
+/// The objects of a chain usually have different types, so a mapper implements this trait once
+/// for every type it can transform, with the same error type. This trait is also implemented for
+/// closures, which can be used if every object has the same type.
+pub trait TryMapper<T> {
+    /// The type of the transformed object.
+    type Output;
+
+    /// The error returned if the object can't be transformed.
+    type Error;
+
+    /// Transforms `object`.
+    fn try_map(&mut self, object: T) -> Result<Self::Output, Self::Error>;
+}
+
+impl<T, O, E, F: FnMut(T) -> Result<O, E>> TryMapper<T> for F {
+    type Output = O;
+    type Error = E;
+
+    #[inline]
+    fn try_map(&mut self, object: T) -> Result<O, E> {
+        self(object)
+    }
+}
+
+/// Implemented by chains whose every object can be transformed by the mapper `M`.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, TryMapper};
+///
+/// struct UartBuilder {
+///     baud: u32,
+/// }
+///
+/// struct SpiBuilder {
+///     frequency: u32,
+/// }
+///
+/// struct Uart;
+/// struct Spi;
+///
+/// struct Build;
+///
+/// impl TryMapper<UartBuilder> for Build {
+///     type Output = Uart;
+///     type Error = &'static str;
+///
+///     fn try_map(&mut self, builder: UartBuilder) -> Result<Uart, &'static str> {
+///         if builder.baud > 1_000_000 {
+///             return Err("unsupported baud rate");
+///         }
+///         Ok(Uart)
+///     }
+/// }
+///
+/// impl TryMapper<SpiBuilder> for Build {
+///     type Output = Spi;
+///     type Error = &'static str;
+///
+///     fn try_map(&mut self, builder: SpiBuilder) -> Result<Spi, &'static str> {
+///         if builder.frequency == 0 {
+///             return Err("invalid frequency");
+///         }
+///         Ok(Spi)
+///     }
+/// }
+///
+/// let builders = Chain::new(UartBuilder { baud: 115_200 }).append(SpiBuilder { frequency: 0 });
+///
+/// assert_eq!(Some("invalid frequency"), builders.try_map(Build).err());
+/// ```
+pub trait TryMapChain<M>: ChainElement {
+    /// The chain of transformed objects.
+    type Mapped: ChainElement;
+
+    /// The error returned by the mapper.
+    type Error;
+
+    /// Transforms every object, in the order they were appended, stopping at the first error.
+    fn try_map_with(self, mapper: &mut M) -> Result<Self::Mapped, Self::Error>;
+}
+
+impl<V, M: TryMapper<V>> TryMapChain<M> for Chain<V> {
+    type Mapped = Chain<M::Output>;
+    type Error = M::Error;
+
+    #[inline]
+    fn try_map_with(self, mapper: &mut M) -> Result<Self::Mapped, M::Error> {
+        mapper.try_map(self.object).map(Chain::new)
+    }
+}
+
+impl<V, C, M> TryMapChain<M> for Link<V, C>
+where
+    M: TryMapper<V>,
+    C: TryMapChain<M, Error = M::Error>,
+{
+    type Mapped = Link<M::Output, C::Mapped>;
+    type Error = M::Error;
+
+    #[inline]
+    fn try_map_with(self, mapper: &mut M) -> Result<Self::Mapped, M::Error> {
+        let parent = self.parent.try_map_with(mapper)?;
+        let object = mapper.try_map(self.object)?;
+        Ok(Link { parent, object })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement};
+
+    #[test]
+    pub fn test_map() {
+        let chain = Chain::new(1u8).append(2u8).append(3u8);
+
+        let mut visited = 0;
+        let doubled = chain.map_ref(|object: &u8| {
+            visited += 1;
+            *object as u16 * 2
+        });
+
+        assert_eq!(3, visited);
+        assert_eq!(Chain::new(2u16).append(4).append(6), doubled);
+        assert_eq!(
+            Chain::new(true).append(false).append(true),
+            chain.map(|object: u8| object % 2 == 1)
+        );
+    }
+
+    #[test]
+    pub fn test_scan() {
+        let sizes = Chain::new(4usize).append(2).append(8);
+
+        let offsets = sizes.scan(0, |offset: &mut usize, size: usize| {
+            let start = *offset;
+            *offset += size;
+            start..*offset
+        });
+
+        assert_eq!(Chain::new(0..4).append(4..6).append(6..14), offsets);
+    }
+
+    #[test]
+    pub fn test_try_map() {
+        let mut calls = 0;
+        let mut scale = |object: u8| {
+            calls += 1;
+            object.checked_mul(100).ok_or(object)
+        };
+
+        let mapped = Chain::new(1u8).append(2).try_map(&mut scale);
+        assert_eq!(Ok(Chain::new(100).append(200)), mapped);
+
+        let mapped = Chain::new(3u8).append(1).append(4).try_map(&mut scale);
+        assert_eq!(Err(3), mapped);
+
+        assert_eq!(3, calls);
+    }
+}
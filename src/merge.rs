@@ -0,0 +1,210 @@
+//! Merging chains with override semantics.
+
+use crate::{cmp, private, Chain, ChainElement, Compare, Keep, Link, Skip, SortKey};
+
+/// Implemented by chains of [`SortKey`] objects that can be merged with the chain `Rhs`.
+///
+/// The merged chain contains the objects of the chain whose keys don't appear in `Rhs`, in the
+/// order they were appended, followed by every object of `Rhs`. Objects whose keys appear in both
+/// chains are taken from `Rhs`, so every key is present only once, as long as the keys are unique
+/// within both chains. This models layered configuration, where a chain of defaults is merged with
+/// a chain that overrides some of them, or adds new ones.
+///
+/// Types are identified by their [`SortKey`]s, see
+/// [type-level limitations](crate#type-level-limitations). Use [`ChainElement::canonicalize`] to
+/// sort the merged chain into the same type, regardless of which objects were overridden.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, Const, SortKey, ToNat};
+///
+/// struct BaudRate(u32);
+/// struct Parity(bool);
+/// struct StopBits(u8);
+/// struct FlowControl(bool);
+///
+/// impl SortKey for BaudRate {
+///     type Key = <Const<0> as ToNat>::Nat;
+/// }
+///
+/// impl SortKey for Parity {
+///     type Key = <Const<1> as ToNat>::Nat;
+/// }
+///
+/// impl SortKey for StopBits {
+///     type Key = <Const<2> as ToNat>::Nat;
+/// }
+///
+/// impl SortKey for FlowControl {
+///     type Key = <Const<3> as ToNat>::Nat;
+/// }
+///
+/// let defaults = Chain::new(BaudRate(9600)).append(Parity(false)).append(StopBits(1));
+/// let overrides = Chain::new(StopBits(2)).append(FlowControl(true)).append(BaudRate(115_200));
+///
+/// let config = defaults.merge(overrides).canonicalize();
+///
+/// assert_eq!(4, config.len());
+/// assert_eq!(115_200, config.parent.parent.parent.object.0);
+/// assert!(!config.parent.parent.object.0);
+/// assert_eq!(2, config.parent.object.0);
+/// assert!(config.object.0);
+/// ```
+pub trait Merge<Rhs>: ChainElement {
+    /// The merged chain.
+    type Merged: ChainElement;
+
+    /// Merges the chain with `other`, replacing the objects whose keys appear in `other`.
+    fn merge_with(self, other: Rhs) -> Self::Merged;
+}
+
+impl<S, Rhs> Merge<Rhs> for S
+where
+    S: ChainElement + private::Unshadowed<Rhs>,
+    S::Kept: private::PushChain<Rhs>,
+{
+    type Merged = <S::Kept as private::PushChain<Rhs>>::Pushed;
+
+    #[inline]
+    fn merge_with(self, other: Rhs) -> Self::Merged {
+        <S::Kept as private::PushChain<Rhs>>::push_chain(self.unshadowed(), other)
+    }
+}
+
+// An object is kept unless an object of the other chain has the same key.
+impl private::ShadowOrdering<Keep> for cmp::Equal {
+    type Decision = Skip;
+}
+
+impl private::ShadowOrdering<Skip> for cmp::Equal {
+    type Decision = Skip;
+}
+
+impl<D> private::ShadowOrdering<D> for cmp::Less {
+    type Decision = D;
+}
+
+impl<D> private::ShadowOrdering<D> for cmp::Greater {
+    type Decision = D;
+}
+
+impl<K, R> private::Shadows<K> for Chain<R>
+where
+    R: SortKey,
+    K: Compare<R::Key>,
+    K::Ordering: private::ShadowOrdering<Keep>,
+{
+    type Decision = <K::Ordering as private::ShadowOrdering<Keep>>::Decision;
+}
+
+impl<K, R, C> private::Shadows<K> for Link<R, C>
+where
+    R: SortKey,
+    C: ChainElement + private::Shadows<K>,
+    K: Compare<R::Key>,
+    K::Ordering: private::ShadowOrdering<C::Decision>,
+{
+    type Decision = <K::Ordering as private::ShadowOrdering<C::Decision>>::Decision;
+}
+
+impl<V, Rhs> private::Unshadowed<Rhs> for Chain<V>
+where
+    V: SortKey,
+    Rhs: private::Shadows<V::Key>,
+    Rhs::Decision: private::FilterStep<V, ()>,
+{
+    type Kept = <Rhs::Decision as private::FilterStep<V, ()>>::Output;
+
+    #[inline]
+    fn unshadowed(self) -> Self::Kept {
+        <Rhs::Decision as private::FilterStep<V, ()>>::step((), self.object)
+    }
+}
+
+impl<V, C, Rhs> private::Unshadowed<Rhs> for Link<V, C>
+where
+    V: SortKey,
+    C: ChainElement + private::Unshadowed<Rhs>,
+    Rhs: private::Shadows<V::Key>,
+    Rhs::Decision: private::FilterStep<V, C::Kept>,
+{
+    type Kept = <Rhs::Decision as private::FilterStep<V, C::Kept>>::Output;
+
+    #[inline]
+    fn unshadowed(self) -> Self::Kept {
+        let kept = self.parent.unshadowed();
+        <Rhs::Decision as private::FilterStep<V, C::Kept>>::step(kept, self.object)
+    }
+}
+
+impl<V, Acc> private::PushChain<Chain<V>> for Acc
+where
+    Acc: private::Push<V>,
+    Acc::Pushed: ChainElement,
+{
+    type Pushed = Acc::Pushed;
+
+    #[inline]
+    fn push_chain(self, chain: Chain<V>) -> Self::Pushed {
+        self.push(chain.object)
+    }
+}
+
+impl<V, C, Acc> private::PushChain<Link<V, C>> for Acc
+where
+    C: ChainElement,
+    Acc: private::PushChain<C>,
+    Acc::Pushed: private::Push<V>,
+    <Acc::Pushed as private::Push<V>>::Pushed: ChainElement,
+{
+    type Pushed = <Acc::Pushed as private::Push<V>>::Pushed;
+
+    #[inline]
+    fn push_chain(self, chain: Link<V, C>) -> Self::Pushed {
+        let pushed = <Acc as private::PushChain<C>>::push_chain(self, chain.parent);
+        private::Push::push(pushed, chain.object)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{assert_chain_eq, Chain, ChainElement, Const, SortKey, ToNat};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Keyed<const KEY: usize>(u8);
+
+    impl<const KEY: usize> SortKey for Keyed<KEY>
+    where
+        Const<KEY>: ToNat,
+    {
+        type Key = <Const<KEY> as ToNat>::Nat;
+    }
+
+    #[test]
+    pub fn test_merge() {
+        let defaults = Chain::new(Keyed::<1>(1))
+            .append(Keyed::<2>(2))
+            .append(Keyed::<3>(3));
+
+        assert_chain_eq!(
+            defaults.merge(Chain::new(Keyed::<3>(30)).append(Keyed::<1>(10))),
+            (Keyed::<2>(2), Keyed::<3>(30), Keyed::<1>(10))
+        );
+        assert_chain_eq!(
+            defaults.merge(Chain::new(Keyed::<2>(20))),
+            (Keyed::<1>(1), Keyed::<3>(3), Keyed::<2>(20))
+        );
+    }
+
+    #[test]
+    pub fn test_merge_new_types() {
+        let defaults = Chain::new(Keyed::<1>(1)).append(Keyed::<2>(2));
+
+        let merged = defaults.merge(Chain::new(Keyed::<4>(4)).append(Keyed::<2>(20)));
+        assert_chain_eq!(merged, (Keyed::<1>(1), Keyed::<4>(4), Keyed::<2>(20)));
+
+        let merged = Chain::new(Keyed::<1>(1)).merge(Chain::new(Keyed::<1>(10)));
+        assert_chain_eq!(merged, (Keyed::<1>(10),));
+    }
+}
@@ -0,0 +1,217 @@
+//! Type-level natural numbers.
+
+use core::marker::PhantomData;
+
+use crate::private;
+
+/// A natural number encoded in the type system.
+///
+/// Type-level numbers are built from [`Zero`] and [`Succ`], e.g. `Succ<Succ<Zero>>` is 2. Use
+/// [`Const`] to convert an integer literal into a type-level number.
+pub trait Nat: private::SealedNat {
+    /// The value of the number.
+    const VALUE: usize;
+}
+
+/// The type-level number 0.
+pub struct Zero;
+
+/// The type-level number `N + 1`.
+pub struct Succ<N>(PhantomData<N>);
+
+impl Nat for Zero {
+    const VALUE: usize = 0;
+}
+
+impl<N: Nat> Nat for Succ<N> {
+    const VALUE: usize = N::VALUE + 1;
+}
+
+/// An integer that can be converted into a type-level number using [`ToNat`].
+pub struct Const<const N: usize>;
+
+/// Conversion into a type-level number.
+///
+/// This trait is implemented for [`Const`] with values up to 32.
+pub trait ToNat {
+    /// The type-level number.
+    type Nat: Nat;
+}
+
+impl ToNat for Const<0> {
+    type Nat = Zero;
+}
+
+impl ToNat for Const<1> {
+    type Nat = Succ<<Const<0> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<2> {
+    type Nat = Succ<<Const<1> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<3> {
+    type Nat = Succ<<Const<2> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<4> {
+    type Nat = Succ<<Const<3> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<5> {
+    type Nat = Succ<<Const<4> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<6> {
+    type Nat = Succ<<Const<5> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<7> {
+    type Nat = Succ<<Const<6> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<8> {
+    type Nat = Succ<<Const<7> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<9> {
+    type Nat = Succ<<Const<8> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<10> {
+    type Nat = Succ<<Const<9> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<11> {
+    type Nat = Succ<<Const<10> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<12> {
+    type Nat = Succ<<Const<11> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<13> {
+    type Nat = Succ<<Const<12> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<14> {
+    type Nat = Succ<<Const<13> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<15> {
+    type Nat = Succ<<Const<14> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<16> {
+    type Nat = Succ<<Const<15> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<17> {
+    type Nat = Succ<<Const<16> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<18> {
+    type Nat = Succ<<Const<17> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<19> {
+    type Nat = Succ<<Const<18> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<20> {
+    type Nat = Succ<<Const<19> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<21> {
+    type Nat = Succ<<Const<20> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<22> {
+    type Nat = Succ<<Const<21> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<23> {
+    type Nat = Succ<<Const<22> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<24> {
+    type Nat = Succ<<Const<23> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<25> {
+    type Nat = Succ<<Const<24> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<26> {
+    type Nat = Succ<<Const<25> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<27> {
+    type Nat = Succ<<Const<26> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<28> {
+    type Nat = Succ<<Const<27> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<29> {
+    type Nat = Succ<<Const<28> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<30> {
+    type Nat = Succ<<Const<29> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<31> {
+    type Nat = Succ<<Const<30> as ToNat>::Nat>;
+}
+
+impl ToNat for Const<32> {
+    type Nat = Succ<<Const<31> as ToNat>::Nat>;
+}
+
+/// The result of comparing two type-level numbers.
+pub mod cmp {
+    /// The left hand side is less than the right hand side.
+    pub struct Less;
+
+    /// The two sides are equal.
+    pub struct Equal;
+
+    /// The left hand side is greater than the right hand side.
+    pub struct Greater;
+}
+
+/// Compares two type-level numbers.
+pub trait Compare<Rhs> {
+    /// One of the types in [`cmp`].
+    type Ordering;
+}
+
+impl Compare<Zero> for Zero {
+    type Ordering = cmp::Equal;
+}
+
+impl<N> Compare<Succ<N>> for Zero {
+    type Ordering = cmp::Less;
+}
+
+impl<N> Compare<Zero> for Succ<N> {
+    type Ordering = cmp::Greater;
+}
+
+impl<N: Compare<M>, M> Compare<Succ<M>> for Succ<N> {
+    type Ordering = N::Ordering;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_value() {
+        assert_eq!(0, Zero::VALUE);
+        assert_eq!(2, <Succ<Succ<Zero>>>::VALUE);
+        assert_eq!(32, <<Const<32> as ToNat>::Nat as Nat>::VALUE);
+    }
+}
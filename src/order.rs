@@ -0,0 +1,56 @@
+//! Enforcing the order of objects at compile time.
+
+/// Marks that an object of this type may be appended right after an object of type `Previous`.
+///
+/// Implement this trait for the stages of a pipeline and use [`ChainElement::append_after`] to
+/// build the pipeline, so that stages appended in an invalid order fail to compile.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{After, Chain, ChainElement};
+///
+/// struct Framing;
+/// struct Crc;
+///
+/// impl After<Framing> for Crc {}
+///
+/// let pipeline = Chain::new(Framing).append_after(Crc);
+/// assert_eq!(2, pipeline.len());
+/// ```
+///
+/// Appending the stages in the wrong order is a compile error:
+///
+/// ```rust,compile_fail
+/// # use object_chain::{After, Chain, ChainElement};
+/// # struct Framing;
+/// # struct Crc;
+/// # impl After<Framing> for Crc {}
+/// let pipeline = Chain::new(Crc).append_after(Framing);
+/// ```
+///
+/// [`ChainElement::append_after`]: crate::ChainElement::append_after
+#[diagnostic::on_unimplemented(message = "`{Self}` can't be appended after `{Previous}`")]
+pub trait After<Previous: ?Sized> {}
+
+#[cfg(test)]
+mod test {
+    use crate::{After, Chain, ChainElement};
+
+    struct Decode;
+    struct Validate;
+    struct Dispatch;
+
+    impl After<Decode> for Validate {}
+    impl After<Validate> for Dispatch {}
+
+    #[test]
+    pub fn test_append_after() {
+        let pipeline = Chain::new(Decode)
+            .append_after(Validate)
+            .append_after(Dispatch)
+            .append(0u8);
+
+        assert_eq!(4, pipeline.len());
+    }
+}
@@ -0,0 +1,150 @@
+//! Using a chain as a registry of peripherals.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A hardware peripheral that can be powered up and down.
+pub trait Peripheral {
+    /// Powers up the peripheral.
+    fn power_up(&mut self);
+
+    /// Powers down the peripheral.
+    fn power_down(&mut self);
+
+    /// Returns whether the peripheral is powered up and ready to be used.
+    fn is_ready(&self) -> bool;
+}
+
+/// A chain of [`Peripheral`] objects.
+///
+/// This trait is implemented for every chain whose objects all implement [`Peripheral`]. Use
+/// [`ChainElement::get_of_mut`] to access a specific peripheral.
+pub trait PeripheralChain: ChainElement {
+    /// Powers up every peripheral, in the order they were appended.
+    fn enable_all(&mut self);
+
+    /// Powers down every peripheral, in the reverse order they were appended.
+    fn disable_all(&mut self);
+
+    /// Returns whether every peripheral is ready to be used.
+    fn all_ready(&self) -> bool;
+}
+
+impl<P: Peripheral> PeripheralChain for Chain<P> {
+    #[inline]
+    fn enable_all(&mut self) {
+        self.object.power_up();
+    }
+
+    #[inline]
+    fn disable_all(&mut self) {
+        self.object.power_down();
+    }
+
+    #[inline]
+    fn all_ready(&self) -> bool {
+        self.object.is_ready()
+    }
+}
+
+impl<P: Peripheral, C: PeripheralChain> PeripheralChain for Link<P, C> {
+    #[inline]
+    fn enable_all(&mut self) {
+        self.parent.enable_all();
+        self.object.power_up();
+    }
+
+    #[inline]
+    fn disable_all(&mut self) {
+        self.object.power_down();
+        self.parent.disable_all();
+    }
+
+    #[inline]
+    fn all_ready(&self) -> bool {
+        self.parent.all_ready() && self.object.is_ready()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::cell::Cell;
+
+    struct Uart<'a> {
+        powered: bool,
+        order: &'a Cell<u32>,
+        sequence: u32,
+    }
+
+    struct Backlight<'a> {
+        level: u8,
+        order: &'a Cell<u32>,
+        sequence: u32,
+    }
+
+    impl Peripheral for Uart<'_> {
+        fn power_up(&mut self) {
+            self.powered = true;
+            self.sequence = self.order.get();
+            self.order.set(self.sequence + 1);
+        }
+
+        fn power_down(&mut self) {
+            self.powered = false;
+            self.sequence = self.order.get();
+            self.order.set(self.sequence + 1);
+        }
+
+        fn is_ready(&self) -> bool {
+            self.powered
+        }
+    }
+
+    impl Peripheral for Backlight<'_> {
+        fn power_up(&mut self) {
+            self.level = 1;
+            self.sequence = self.order.get();
+            self.order.set(self.sequence + 1);
+        }
+
+        fn power_down(&mut self) {
+            self.level = 0;
+            self.sequence = self.order.get();
+            self.order.set(self.sequence + 1);
+        }
+
+        fn is_ready(&self) -> bool {
+            self.level > 0
+        }
+    }
+
+    #[test]
+    pub fn test_enable_disable_all() {
+        let order = Cell::new(0);
+        let mut chain = Chain::new(Uart {
+            powered: false,
+            order: &order,
+            sequence: 0,
+        })
+        .append(Backlight {
+            level: 0,
+            order: &order,
+            sequence: 0,
+        });
+
+        assert!(!chain.all_ready());
+
+        chain.enable_all();
+        assert!(chain.all_ready());
+        assert_eq!(0, chain.get_of::<Uart, _>().sequence);
+        assert_eq!(1, chain.get_of::<Backlight, _>().sequence);
+
+        chain.get_of_mut::<Backlight, _>().level = 5;
+        assert_eq!(5, chain.object.level);
+
+        chain.disable_all();
+        assert!(!chain.get_of::<Uart, _>().is_ready());
+        assert_eq!(3, chain.get_of::<Uart, _>().sequence);
+        assert_eq!(2, chain.get_of::<Backlight, _>().sequence);
+    }
+}
@@ -0,0 +1,268 @@
+//! Saving and restoring the state of a chain.
+
+use crate::{Chain, ChainElement, Link};
+
+/// An object whose state can be saved to and restored from a byte buffer.
+///
+/// Chains of `Persist` objects implement `Persist` themselves. A chain saves its objects in the
+/// order they were appended, as a sequence of records. Every record starts with the length of the
+/// object's data and the [`Persist::VERSION`] of the object, both as little endian `u16`s, followed
+/// by the data itself.
+///
+/// When a record was saved by a different version of the object, e.g. before a firmware update
+/// changed the object's layout, the chain calls [`Persist::migrate`] instead of [`Persist::load`].
+/// The other objects of the chain are restored as usual.
+pub trait Persist {
+    /// The version of the layout of the saved data.
+    ///
+    /// Change the version whenever the layout changes, and handle the old layouts in
+    /// [`Persist::migrate`].
+    const VERSION: u16 = 0;
+
+    /// Saves the state of the object into `buffer` and returns the number of bytes written.
+    ///
+    /// Implementations may panic if `buffer` is too small.
+    fn save(&self, buffer: &mut [u8]) -> usize;
+
+    /// Restores the state of the object from `buffer`, which contains the data written by
+    /// [`Persist::save`].
+    fn load(&mut self, buffer: &[u8]);
+
+    /// Restores the state of the object from `buffer`, which contains the data written by a
+    /// version of the object whose [`Persist::VERSION`] was `old_version`.
+    ///
+    /// The default implementation leaves the object unchanged, discarding the saved state.
+    #[inline]
+    fn migrate(&mut self, old_version: u16, buffer: &[u8]) {
+        let _ = (old_version, buffer);
+    }
+}
+
+/// A chain of [`Persist`] objects.
+///
+/// You will usually use [`Persist::save`] and [`Persist::load`] on the chain instead of these
+/// methods.
+pub trait PersistChain: ChainElement {
+    /// Saves the record of every object into `buffer`, and returns the number of bytes written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is too small, or if an object writes more than `u16::MAX` bytes.
+    fn save_records(&self, buffer: &mut [u8]) -> usize;
+
+    /// Restores every object from the records in `buffer`, and returns the number of bytes read.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` does not contain a record for every object.
+    fn load_records(&mut self, buffer: &[u8]) -> usize;
+}
+
+const HEADER_LEN: usize = 4;
+
+#[inline]
+fn save_record<P: Persist>(object: &P, buffer: &mut [u8]) -> usize {
+    let (header, data) = buffer.split_at_mut(HEADER_LEN);
+    let len = object.save(data);
+    assert!(
+        len <= u16::MAX as usize,
+        "the record of an object can be at most 65535 bytes long"
+    );
+    header[..2].copy_from_slice(&(len as u16).to_le_bytes());
+    header[2..].copy_from_slice(&P::VERSION.to_le_bytes());
+    len + HEADER_LEN
+}
+
+#[inline]
+fn load_record<P: Persist>(object: &mut P, buffer: &[u8]) -> usize {
+    let len = u16::from_le_bytes([buffer[0], buffer[1]]) as usize;
+    let version = u16::from_le_bytes([buffer[2], buffer[3]]);
+    let data = &buffer[HEADER_LEN..HEADER_LEN + len];
+    if version == P::VERSION {
+        object.load(data);
+    } else {
+        object.migrate(version, data);
+    }
+    len + HEADER_LEN
+}
+
+impl<P: Persist> PersistChain for Chain<P> {
+    #[inline]
+    fn save_records(&self, buffer: &mut [u8]) -> usize {
+        save_record(&self.object, buffer)
+    }
+
+    #[inline]
+    fn load_records(&mut self, buffer: &[u8]) -> usize {
+        load_record(&mut self.object, buffer)
+    }
+}
+
+impl<P: Persist, C: PersistChain> PersistChain for Link<P, C> {
+    #[inline]
+    fn save_records(&self, buffer: &mut [u8]) -> usize {
+        let written = self.parent.save_records(buffer);
+        written + save_record(&self.object, &mut buffer[written..])
+    }
+
+    #[inline]
+    fn load_records(&mut self, buffer: &[u8]) -> usize {
+        let read = self.parent.load_records(buffer);
+        read + load_record(&mut self.object, &buffer[read..])
+    }
+}
+
+impl<P: Persist> Persist for Chain<P> {
+    #[inline]
+    fn save(&self, buffer: &mut [u8]) -> usize {
+        self.save_records(buffer)
+    }
+
+    #[inline]
+    fn load(&mut self, buffer: &[u8]) {
+        self.load_records(buffer);
+    }
+}
+
+impl<P: Persist, C: PersistChain> Persist for Link<P, C> {
+    #[inline]
+    fn save(&self, buffer: &mut [u8]) -> usize {
+        self.save_records(buffer)
+    }
+
+    #[inline]
+    fn load(&mut self, buffer: &[u8]) {
+        self.load_records(buffer);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Brightness(u8);
+
+    #[derive(Debug, PartialEq)]
+    struct Calibration {
+        offset: i16,
+        gain: u16,
+    }
+
+    impl Persist for Brightness {
+        fn save(&self, buffer: &mut [u8]) -> usize {
+            buffer[0] = self.0;
+            1
+        }
+
+        fn load(&mut self, buffer: &[u8]) {
+            self.0 = buffer[0];
+        }
+    }
+
+    impl Persist for Calibration {
+        fn save(&self, buffer: &mut [u8]) -> usize {
+            buffer[..2].copy_from_slice(&self.offset.to_le_bytes());
+            buffer[2..4].copy_from_slice(&self.gain.to_le_bytes());
+            4
+        }
+
+        fn load(&mut self, buffer: &[u8]) {
+            self.offset = i16::from_le_bytes([buffer[0], buffer[1]]);
+            self.gain = u16::from_le_bytes([buffer[2], buffer[3]]);
+        }
+    }
+
+    #[test]
+    pub fn test_save_load() {
+        let chain = Chain::new(Brightness(7)).append(Calibration {
+            offset: -2,
+            gain: 300,
+        });
+
+        let mut buffer = [0; 16];
+        let written = chain.save(&mut buffer);
+        assert_eq!(13, written);
+        assert_eq!(
+            [1, 0, 0, 0, 7, 4, 0, 0, 0, 254, 255, 44, 1],
+            buffer[..written]
+        );
+
+        let mut restored = Chain::new(Brightness(0)).append(Calibration { offset: 0, gain: 0 });
+        restored.load(&buffer[..written]);
+
+        assert_eq!(Brightness(7), restored.parent.object);
+        assert_eq!(chain.object, restored.object);
+    }
+
+    #[test]
+    pub fn test_nested_chains() {
+        let chain =
+            Chain::new(Chain::new(Brightness(1)).append(Brightness(2))).append(Brightness(3));
+
+        let mut buffer = [0; 32];
+        let written = chain.save(&mut buffer);
+
+        let mut restored =
+            Chain::new(Chain::new(Brightness(0)).append(Brightness(0))).append(Brightness(0));
+        restored.load(&buffer[..written]);
+
+        assert_eq!(Brightness(1), restored.parent.object.parent.object);
+        assert_eq!(Brightness(2), restored.parent.object.object);
+        assert_eq!(Brightness(3), restored.object);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Threshold(u16);
+
+    // Version 0 saved the threshold as a single byte.
+    impl Persist for Threshold {
+        const VERSION: u16 = 1;
+
+        fn save(&self, buffer: &mut [u8]) -> usize {
+            buffer[..2].copy_from_slice(&self.0.to_le_bytes());
+            2
+        }
+
+        fn load(&mut self, buffer: &[u8]) {
+            self.0 = u16::from_le_bytes([buffer[0], buffer[1]]);
+        }
+
+        fn migrate(&mut self, old_version: u16, buffer: &[u8]) {
+            if old_version == 0 {
+                self.0 = buffer[0] as u16 * 4;
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_migrate() {
+        let old = [1, 0, 0, 0, 7, 1, 0, 0, 0, 50];
+
+        let mut restored = Chain::new(Brightness(0)).append(Threshold(0));
+        restored.load(&old);
+
+        assert_eq!(Brightness(7), restored.parent.object);
+        assert_eq!(Threshold(200), restored.object);
+
+        let mut buffer = [0; 16];
+        let written = restored.save(&mut buffer);
+        assert_eq!([1, 0, 0, 0, 7, 2, 0, 1, 0, 200, 0], buffer[..written]);
+    }
+
+    #[test]
+    #[should_panic(expected = "the record of an object can be at most 65535 bytes long")]
+    pub fn test_save_oversized_record() {
+        struct Blob;
+
+        impl Persist for Blob {
+            fn save(&self, _buffer: &mut [u8]) -> usize {
+                70_000
+            }
+
+            fn load(&mut self, _buffer: &[u8]) {}
+        }
+
+        Chain::new(Blob).save(&mut [0; 8]);
+    }
+}
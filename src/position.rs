@@ -0,0 +1,411 @@
+//! Accessing chain objects by their position.
+
+use crate::{
+    nat::{cmp, Compare, Succ, ToNat, Zero},
+    private, Chain, ChainElement, Link,
+};
+
+/// Implemented by chains that contain an object at the type-level position `P`.
+///
+/// Positions start at [`Zero`] for the first appended object. You will usually use this trait
+/// through [`ChainElement::at`] and [`ChainElement::at_mut`], which accept integer positions.
+pub trait At<P>: ChainElement {
+    /// The type of the object at position `P`.
+    type Output;
+
+    /// Returns a reference to the object at position `P`.
+    fn at_position(&self) -> &Self::Output;
+
+    /// Returns a mutable reference to the object at position `P`.
+    fn at_position_mut(&mut self) -> &mut Self::Output;
+}
+
+/// Implemented by chains whose object at the type-level position `P` can be replaced by an
+/// object of a different type.
+///
+/// You will usually use this trait through [`ChainElement::map_at`], which accepts integer
+/// positions.
+pub trait MapAt<P>: At<P> {
+    /// The type of the chain after the object at position `P` is replaced by an object of type `U`.
+    type Mapped<U>: ChainElement;
+
+    /// Replaces the object at position `P` by the result of `f`.
+    fn map_at_position<U, F>(self, f: F) -> Self::Mapped<U>
+    where
+        F: FnOnce(Self::Output) -> U;
+}
+
+impl<V> At<Zero> for Chain<V> {
+    type Output = V;
+
+    #[inline]
+    fn at_position(&self) -> &V {
+        &self.object
+    }
+
+    #[inline]
+    fn at_position_mut(&mut self) -> &mut V {
+        &mut self.object
+    }
+}
+
+impl<V> MapAt<Zero> for Chain<V> {
+    type Mapped<U> = Chain<U>;
+
+    #[inline]
+    fn map_at_position<U, F>(self, f: F) -> Chain<U>
+    where
+        F: FnOnce(V) -> U,
+    {
+        Chain::new(f(self.object))
+    }
+}
+
+impl<V, C, P> At<P> for Link<V, C>
+where
+    C: ChainElement,
+    P: Compare<C::Depth>,
+    Self: private::AtOrdering<P, P::Ordering>,
+{
+    type Output = <Self as private::AtOrdering<P, P::Ordering>>::Output;
+
+    #[inline]
+    fn at_position(&self) -> &Self::Output {
+        private::AtOrdering::at(self)
+    }
+
+    #[inline]
+    fn at_position_mut(&mut self) -> &mut Self::Output {
+        private::AtOrdering::at_mut(self)
+    }
+}
+
+impl<V, C, P> MapAt<P> for Link<V, C>
+where
+    C: ChainElement,
+    P: Compare<C::Depth>,
+    Self: private::MapAtOrdering<P, P::Ordering>,
+{
+    type Mapped<U> = <Self as private::MapAtOrdering<P, P::Ordering>>::Mapped<U>;
+
+    #[inline]
+    fn map_at_position<U, F>(self, f: F) -> Self::Mapped<U>
+    where
+        F: FnOnce(Self::Output) -> U,
+    {
+        private::MapAtOrdering::map_at(self, f)
+    }
+}
+
+// The object of a link is at the position equal to the length of its parent.
+impl<V, C: ChainElement, P> private::AtOrdering<P, cmp::Equal> for Link<V, C> {
+    type Output = V;
+
+    #[inline]
+    fn at(&self) -> &V {
+        &self.object
+    }
+
+    #[inline]
+    fn at_mut(&mut self) -> &mut V {
+        &mut self.object
+    }
+}
+
+impl<V, C: ChainElement, P> private::MapAtOrdering<P, cmp::Equal> for Link<V, C> {
+    type Mapped<U> = Link<U, C>;
+
+    #[inline]
+    fn map_at<U, F>(self, f: F) -> Link<U, C>
+    where
+        F: FnOnce(V) -> U,
+    {
+        Link {
+            parent: self.parent,
+            object: f(self.object),
+        }
+    }
+}
+
+// Positions less than the length of the parent are in the parent.
+impl<V, C: At<P>, P> private::AtOrdering<P, cmp::Less> for Link<V, C> {
+    type Output = C::Output;
+
+    #[inline]
+    fn at(&self) -> &C::Output {
+        self.parent.at_position()
+    }
+
+    #[inline]
+    fn at_mut(&mut self) -> &mut C::Output {
+        self.parent.at_position_mut()
+    }
+}
+
+impl<V, C: MapAt<P>, P> private::MapAtOrdering<P, cmp::Less> for Link<V, C> {
+    type Mapped<U> = Link<V, C::Mapped<U>>;
+
+    #[inline]
+    fn map_at<U, F>(self, f: F) -> Self::Mapped<U>
+    where
+        F: FnOnce(C::Output) -> U,
+    {
+        Link {
+            parent: self.parent.map_at_position(f),
+            object: self.object,
+        }
+    }
+}
+
+/// Implemented by types that contain an object at the given path.
+///
+/// A path is a tuple of positions, given as [`Const`] values. The first position selects an object
+/// of this chain, the second one selects an object of the chain selected by the first position,
+/// and so on. You will usually use this trait through [`ChainElement::at_path`].
+///
+/// This trait is implemented for paths of up to 8 positions. The empty path selects the whole
+/// chain.
+///
+/// [`Const`]: crate::Const
+pub trait AtPath<Path> {
+    /// The type of the object at the end of the path.
+    type Output;
+
+    /// Returns a reference to the object at the end of the path.
+    fn get_path(&self) -> &Self::Output;
+
+    /// Returns a mutable reference to the object at the end of the path.
+    fn get_path_mut(&mut self) -> &mut Self::Output;
+}
+
+impl<T> AtPath<()> for T {
+    type Output = T;
+
+    #[inline]
+    fn get_path(&self) -> &T {
+        self
+    }
+
+    #[inline]
+    fn get_path_mut(&mut self) -> &mut T {
+        self
+    }
+}
+
+macro_rules! impl_at_path {
+    () => {};
+    ($first:ident $(, $rest:ident)*) => {
+        impl<C, $first, $($rest),*> AtPath<($first, $($rest,)*)> for C
+        where
+            $first: ToNat + 'static,
+            C: At<$first::Nat>,
+            <C as At<$first::Nat>>::Output: AtPath<($($rest,)*)>,
+        {
+            type Output = <<C as At<$first::Nat>>::Output as AtPath<($($rest,)*)>>::Output;
+
+            #[inline]
+            fn get_path(&self) -> &Self::Output {
+                self.at_position().get_path()
+            }
+
+            #[inline]
+            fn get_path_mut(&mut self) -> &mut Self::Output {
+                self.at_position_mut().get_path_mut()
+            }
+        }
+
+        impl_at_path!($($rest),*);
+    };
+}
+
+impl_at_path!(P0, P1, P2, P3, P4, P5, P6, P7);
+
+/// Implemented by chains that contain objects at the type-level positions `Start..End`.
+///
+/// You will usually use this trait through [`ChainElement::sub_chain`], which accepts integer
+/// positions.
+pub trait SubChain<'a, Start, End>: ChainElement + 'a {
+    /// A chain of references to the objects in the range.
+    type Refs: ChainElement;
+
+    /// Returns a chain of references to the objects in the range.
+    fn borrow_range(&'a self) -> Self::Refs;
+}
+
+impl<'a, C, S, E> SubChain<'a, S, Succ<E>> for C
+where
+    C: ChainElement + private::SubChainOrdering<'a, S, E, <S as Compare<E>>::Ordering> + 'a,
+    S: Compare<E>,
+{
+    type Refs = <C as private::SubChainOrdering<'a, S, E, S::Ordering>>::Refs;
+
+    #[inline]
+    fn borrow_range(&'a self) -> Self::Refs {
+        private::SubChainOrdering::sub_chain(self)
+    }
+}
+
+// A range that ends right after its start contains a single object.
+impl<'a, C, S, E> private::SubChainOrdering<'a, S, E, cmp::Equal> for C
+where
+    C: At<E> + 'a,
+    C::Output: 'a,
+{
+    type Refs = Chain<&'a C::Output>;
+
+    #[inline]
+    fn sub_chain(&'a self) -> Self::Refs {
+        Chain::new(self.at_position())
+    }
+}
+
+// Longer ranges are built from the range that ends one position earlier.
+impl<'a, C, S, E> private::SubChainOrdering<'a, S, E, cmp::Less> for C
+where
+    C: At<E> + SubChain<'a, S, E>,
+    C::Output: 'a,
+{
+    type Refs = Link<&'a C::Output, C::Refs>;
+
+    #[inline]
+    fn sub_chain(&'a self) -> Self::Refs {
+        self.borrow_range().append(self.at_position())
+    }
+}
+
+/// Implemented by chains that contain at least `N` objects, where `N` is a type-level number.
+///
+/// The prefix of a chain is the chain its first `N` objects were appended to, so it can be
+/// borrowed without copying or wrapping the objects. This can be used to give code access to the
+/// part of a chain that is already initialized. You will usually use this trait through
+/// [`ChainElement::prefix`], which accepts an integer length.
+pub trait ChainPrefix<N>: ChainElement {
+    /// The chain of the first `N` objects.
+    type Prefix: ChainElement;
+
+    /// Returns a reference to the chain of the first `N` objects.
+    fn borrow_prefix(&self) -> &Self::Prefix;
+
+    /// Returns a mutable reference to the chain of the first `N` objects.
+    fn borrow_prefix_mut(&mut self) -> &mut Self::Prefix;
+}
+
+impl<C, N> ChainPrefix<N> for C
+where
+    C: ChainElement + private::PrefixOrdering<N, <N as Compare<C::Depth>>::Ordering>,
+    N: Compare<C::Depth>,
+{
+    type Prefix = <C as private::PrefixOrdering<N, N::Ordering>>::Prefix;
+
+    #[inline]
+    fn borrow_prefix(&self) -> &Self::Prefix {
+        private::PrefixOrdering::prefix(self)
+    }
+
+    #[inline]
+    fn borrow_prefix_mut(&mut self) -> &mut Self::Prefix {
+        private::PrefixOrdering::prefix_mut(self)
+    }
+}
+
+// A prefix as long as the chain is the chain itself.
+impl<C: ChainElement, N> private::PrefixOrdering<N, cmp::Equal> for C {
+    type Prefix = C;
+
+    #[inline]
+    fn prefix(&self) -> &C {
+        self
+    }
+
+    #[inline]
+    fn prefix_mut(&mut self) -> &mut C {
+        self
+    }
+}
+
+// Shorter prefixes are prefixes of the parent.
+impl<V, C: ChainPrefix<N>, N> private::PrefixOrdering<N, cmp::Less> for Link<V, C> {
+    type Prefix = C::Prefix;
+
+    #[inline]
+    fn prefix(&self) -> &C::Prefix {
+        self.parent.borrow_prefix()
+    }
+
+    #[inline]
+    fn prefix_mut(&mut self) -> &mut C::Prefix {
+        self.parent.borrow_prefix_mut()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement, Const};
+
+    struct UninitDriver(u8);
+    struct Driver(u8);
+
+    #[test]
+    pub fn test_at() {
+        let mut chain = Chain::new(1u8).append(2u16).append(3u32);
+
+        assert_eq!(1, *chain.at::<0>());
+        assert_eq!(2, *chain.at::<1>());
+        assert_eq!(3, *chain.at::<2>());
+
+        *chain.at_mut::<1>() = 5;
+        assert_eq!(5, chain.parent.object);
+    }
+
+    #[test]
+    pub fn test_map_at() {
+        let chain = Chain::new(1u8).append(UninitDriver(2)).append(3u32);
+
+        let chain = chain.map_at::<1, _>(|driver| Driver(driver.0 * 2));
+        let driver: &Driver = chain.at::<1>();
+        assert_eq!(4, driver.0);
+
+        let chain = chain.map_at::<0, _>(|object| object as u64);
+        assert_eq!(1u64, *chain.at::<0>());
+        assert_eq!(3, chain.len());
+    }
+
+    #[test]
+    pub fn test_at_path() {
+        let sensors = Chain::new(1u8).append(2u16).append(3u32);
+        let actuators = Chain::new(4u8).append(5u16);
+        let mut system = Chain::new(sensors).append(actuators).append(6u64);
+
+        assert_eq!(3, *system.at_path::<(Const<0>, Const<2>)>());
+        assert_eq!(5, *system.at_path::<(Const<1>, Const<1>)>());
+        assert_eq!(6, *system.at_path::<(Const<2>,)>());
+
+        *system.at_path_mut::<(Const<1>, Const<0>)>() = 7;
+        assert_eq!(7, system.parent.object.parent.object);
+    }
+
+    #[test]
+    pub fn test_prefix() {
+        let mut chain = Chain::new(1u8).append(2u16).append(3u32);
+
+        assert_eq!(1, chain.prefix::<1>().len());
+        assert_eq!(2, chain.prefix::<2>().object);
+        assert_eq!(3, chain.prefix::<3>().object);
+
+        chain.prefix_mut::<2>().parent.object = 4;
+        assert_eq!(4, *chain.at::<0>());
+    }
+
+    #[test]
+    pub fn test_sub_chain() {
+        let chain = Chain::new(1u8).append(2u16).append(3u32).append(4u64);
+
+        let middle = chain.sub_chain::<1, 3>();
+        assert_eq!(2, middle.len());
+        assert_eq!(2, **middle.at::<0>());
+        assert_eq!(3, **middle.at::<1>());
+
+        let last = chain.sub_chain::<3, 4>();
+        assert_eq!(4, *last.object);
+    }
+}
@@ -0,0 +1,252 @@
+//! Sequencing the suspension and resumption of drivers.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A driver that can be suspended, e.g. before entering a low power mode.
+pub trait PowerManaged {
+    /// The error returned if the driver can't be suspended or resumed.
+    type Error;
+
+    /// Suspends the driver.
+    fn suspend(&mut self) -> Result<(), Self::Error>;
+
+    /// Resumes the driver after it was suspended.
+    fn resume(&mut self) -> Result<(), Self::Error>;
+}
+
+/// A chain of [`PowerManaged`] drivers with a common error type.
+///
+/// Drivers are suspended in the reverse order they were appended, and resumed in the order they
+/// were appended, so drivers can depend on the drivers that were appended before them. Both
+/// operations are all-or-nothing: if a driver fails, the drivers that were already processed are
+/// returned to their previous state, and the error is returned. Errors returned while rolling back
+/// are ignored.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, PowerChain, PowerManaged};
+///
+/// struct Clock {
+///     running: bool,
+/// }
+///
+/// struct Radio {
+///     busy: bool,
+///     sleeping: bool,
+/// }
+///
+/// impl PowerManaged for Clock {
+///     type Error = &'static str;
+///
+///     fn suspend(&mut self) -> Result<(), Self::Error> {
+///         self.running = false;
+///         Ok(())
+///     }
+///
+///     fn resume(&mut self) -> Result<(), Self::Error> {
+///         self.running = true;
+///         Ok(())
+///     }
+/// }
+///
+/// impl PowerManaged for Radio {
+///     type Error = &'static str;
+///
+///     fn suspend(&mut self) -> Result<(), Self::Error> {
+///         if self.busy {
+///             return Err("radio is busy");
+///         }
+///         self.sleeping = true;
+///         Ok(())
+///     }
+///
+///     fn resume(&mut self) -> Result<(), Self::Error> {
+///         self.sleeping = false;
+///         Ok(())
+///     }
+/// }
+///
+/// let mut drivers = Chain::new(Clock { running: true }).append(Radio {
+///     busy: false,
+///     sleeping: false,
+/// });
+///
+/// drivers.suspend_all().unwrap();
+/// assert!(!drivers.parent.object.running);
+///
+/// drivers.resume_all().unwrap();
+/// drivers.object.busy = true;
+///
+/// assert_eq!(Err("radio is busy"), drivers.suspend_all());
+/// assert!(drivers.parent.object.running);
+/// ```
+pub trait PowerChain: ChainElement {
+    /// The error returned by the drivers.
+    type Error;
+
+    /// Suspends every driver, in the reverse order they were appended.
+    ///
+    /// If a driver fails to suspend, the already suspended drivers are resumed.
+    fn suspend_all(&mut self) -> Result<(), Self::Error>;
+
+    /// Resumes every driver, in the order they were appended.
+    ///
+    /// If a driver fails to resume, the already resumed drivers are suspended again.
+    fn resume_all(&mut self) -> Result<(), Self::Error>;
+
+    /// Suspends every driver, in the reverse order they were appended, ignoring errors.
+    #[doc(hidden)]
+    fn rollback_resume(&mut self);
+}
+
+impl<P: PowerManaged> PowerChain for Chain<P> {
+    type Error = P::Error;
+
+    #[inline]
+    fn suspend_all(&mut self) -> Result<(), P::Error> {
+        self.object.suspend()
+    }
+
+    #[inline]
+    fn resume_all(&mut self) -> Result<(), P::Error> {
+        self.object.resume()
+    }
+
+    #[inline]
+    fn rollback_resume(&mut self) {
+        let _ = self.object.suspend();
+    }
+}
+
+impl<P, C> PowerChain for Link<P, C>
+where
+    P: PowerManaged,
+    C: PowerChain<Error = P::Error>,
+{
+    type Error = P::Error;
+
+    #[inline]
+    fn suspend_all(&mut self) -> Result<(), P::Error> {
+        self.object.suspend()?;
+
+        if let Err(error) = self.parent.suspend_all() {
+            let _ = self.object.resume();
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn resume_all(&mut self) -> Result<(), P::Error> {
+        self.parent.resume_all()?;
+
+        if let Err(error) = self.object.resume() {
+            self.parent.rollback_resume();
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    fn rollback_resume(&mut self) {
+        let _ = self.object.suspend();
+        self.parent.rollback_resume();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::cell::Cell;
+
+    struct Driver<'a> {
+        id: u32,
+        fail: bool,
+        suspended: bool,
+        log: &'a Cell<u32>,
+    }
+
+    impl<'a> Driver<'a> {
+        fn new(id: u32, log: &'a Cell<u32>) -> Self {
+            Self {
+                id,
+                fail: false,
+                suspended: false,
+                log,
+            }
+        }
+
+        fn record(&self) {
+            self.log.set(self.log.get() * 10 + self.id);
+        }
+    }
+
+    impl PowerManaged for Driver<'_> {
+        type Error = u32;
+
+        fn suspend(&mut self) -> Result<(), u32> {
+            self.record();
+            if self.fail {
+                return Err(self.id);
+            }
+            self.suspended = true;
+            Ok(())
+        }
+
+        fn resume(&mut self) -> Result<(), u32> {
+            self.record();
+            if self.fail {
+                return Err(self.id);
+            }
+            self.suspended = false;
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn test_power_sequencing() {
+        let log = Cell::new(0);
+        let mut drivers = Chain::new(Driver::new(1, &log))
+            .append(Driver::new(2, &log))
+            .append(Driver::new(3, &log));
+
+        assert_eq!(Ok(()), drivers.suspend_all());
+        assert_eq!(321, log.replace(0));
+
+        assert_eq!(Ok(()), drivers.resume_all());
+        assert_eq!(123, log.replace(0));
+    }
+
+    #[test]
+    pub fn test_suspend_rollback() {
+        let log = Cell::new(0);
+        let mut drivers = Chain::new(Driver::new(1, &log))
+            .append(Driver::new(2, &log))
+            .append(Driver::new(3, &log));
+        drivers.parent.parent.object.fail = true;
+
+        assert_eq!(Err(1), drivers.suspend_all());
+        assert_eq!(32123, log.get());
+        assert!(!drivers.parent.object.suspended);
+        assert!(!drivers.object.suspended);
+    }
+
+    #[test]
+    pub fn test_resume_rollback() {
+        let log = Cell::new(0);
+        let mut drivers = Chain::new(Driver::new(1, &log))
+            .append(Driver::new(2, &log))
+            .append(Driver::new(3, &log));
+        drivers.suspend_all().unwrap();
+        log.set(0);
+        drivers.object.fail = true;
+
+        assert_eq!(Err(3), drivers.resume_all());
+        assert_eq!(12321, log.get());
+        assert!(drivers.parent.parent.object.suspended);
+        assert!(drivers.parent.object.suspended);
+    }
+}
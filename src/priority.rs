@@ -0,0 +1,224 @@
+//! Visiting chain objects in the order of their priorities.
+
+use crate::{private, Chain, ChainElement, DynChain, Link, Succ, Zero};
+
+/// An object with a priority, used to decide the order in which objects are serviced.
+///
+/// Objects with a higher priority are visited first by [`PriorityChain::for_each_prioritized`].
+pub trait Prioritized {
+    /// The priority of the object.
+    const PRIORITY: u8;
+}
+
+#[doc(hidden)]
+pub struct PriorityNode {
+    priority: u8,
+    parent: Option<&'static PriorityNode>,
+}
+
+/// A chain of [`Prioritized`] objects.
+///
+/// The order of the objects doesn't depend on the order they were appended in, except for objects
+/// with equal priorities, which are visited in the order they were appended. The visiting order is
+/// computed at compile time, but every object is then looked up by its position, which takes time
+/// proportional to the length of the chain. Visiting every object by priority is therefore
+/// quadratic in the length of the chain, which is only noticeable for long chains.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{
+///     impl_erase, priority_order, Chain, ChainElement, Link, PriorityChain, Prioritized,
+/// };
+///
+/// trait Service {
+///     fn service(&mut self) -> &'static str;
+/// }
+///
+/// impl_erase!(dyn Service);
+///
+/// struct Logger;
+/// struct Watchdog;
+///
+/// impl Prioritized for Logger {
+///     const PRIORITY: u8 = 0;
+/// }
+///
+/// impl Service for Logger {
+///     fn service(&mut self) -> &'static str {
+///         "logger"
+///     }
+/// }
+///
+/// impl Prioritized for Watchdog {
+///     const PRIORITY: u8 = 10;
+/// }
+///
+/// impl Service for Watchdog {
+///     fn service(&mut self) -> &'static str {
+///         "watchdog"
+///     }
+/// }
+///
+/// let mut tasks = Chain::new(Logger).append(Watchdog);
+///
+/// let mut serviced = [""; 2];
+/// let mut count = 0;
+/// tasks.for_each_prioritized::<dyn Service, _>(|task| {
+///     serviced[count] = task.service();
+///     count += 1;
+/// });
+///
+/// assert_eq!(["watchdog", "logger"], serviced);
+/// assert_eq!([1, 0], priority_order::<Link<Watchdog, Chain<Logger>>, 2>());
+/// ```
+pub trait PriorityChain: ChainElement + Sized {
+    #[doc(hidden)]
+    const PRIORITY_NODE: PriorityNode;
+
+    /// Calls `f` for every object, as the trait object type `D`, in the order of their priorities.
+    #[inline]
+    fn for_each_prioritized<D, F>(&mut self, mut f: F)
+    where
+        Self: DynChain<D>,
+        Self::Depth: private::VisitRanks<Self, D>,
+        D: ?Sized,
+        F: FnMut(&mut D),
+    {
+        <Self::Depth as private::VisitRanks<Self, D>>::visit_ranks(self, &mut f);
+    }
+}
+
+impl<V: Prioritized> PriorityChain for Chain<V> {
+    const PRIORITY_NODE: PriorityNode = PriorityNode {
+        priority: V::PRIORITY,
+        parent: None,
+    };
+}
+
+impl<V: Prioritized, C: PriorityChain> PriorityChain for Link<V, C> {
+    const PRIORITY_NODE: PriorityNode = PriorityNode {
+        priority: V::PRIORITY,
+        parent: Some(&C::PRIORITY_NODE),
+    };
+}
+
+const fn priority_at(last: &PriorityNode, len: usize, index: usize) -> u8 {
+    let mut node = last;
+    let mut position = len - 1;
+    while position > index {
+        node = match node.parent {
+            Some(parent) => parent,
+            None => panic!("index out of bounds"),
+        };
+        position -= 1;
+    }
+    node.priority
+}
+
+const fn index_at_rank(last: &PriorityNode, len: usize, rank: usize) -> usize {
+    let mut index = 0;
+    while index < len {
+        let priority = priority_at(last, len, index);
+
+        let mut index_rank = 0;
+        let mut other = 0;
+        while other < len {
+            let other_priority = priority_at(last, len, other);
+            if other_priority > priority || (other_priority == priority && other < index) {
+                index_rank += 1;
+            }
+            other += 1;
+        }
+
+        if index_rank == rank {
+            return index;
+        }
+        index += 1;
+    }
+    panic!("rank out of bounds")
+}
+
+/// Returns the positions of the objects of the chain `C`, in the order of their priorities.
+///
+/// `N` must be equal to the length of the chain, which is checked at compile time.
+pub const fn priority_order<C: PriorityChain, const N: usize>() -> [usize; N] {
+    const { assert!(N == C::LEN, "N must be equal to the length of the chain") };
+
+    let mut order = [0; N];
+    let mut rank = 0;
+    while rank < N {
+        order[rank] = index_at_rank(&C::PRIORITY_NODE, N, rank);
+        rank += 1;
+    }
+    order
+}
+
+impl<C, D: ?Sized> private::VisitRanks<C, D> for Zero {
+    #[inline]
+    fn visit_ranks<F: FnMut(&mut D)>(_chain: &mut C, _f: &mut F) {}
+}
+
+impl<N, C, D> private::VisitRanks<C, D> for Succ<N>
+where
+    N: crate::Nat + private::VisitRanks<C, D>,
+    C: PriorityChain + DynChain<D>,
+    D: ?Sized,
+{
+    #[inline]
+    fn visit_ranks<F: FnMut(&mut D)>(chain: &mut C, f: &mut F) {
+        N::visit_ranks(chain, f);
+
+        let index = const { index_at_rank(&C::PRIORITY_NODE, C::LEN, N::VALUE) };
+        if let Some(object) = chain.get_dyn_mut(index) {
+            f(object);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::impl_erase;
+
+    trait Id {
+        fn id(&self) -> u8;
+    }
+
+    impl_erase!(dyn Id);
+
+    struct Task<const ID: u8, const PRIORITY: u8>;
+
+    impl<const ID: u8, const PRIORITY: u8> Prioritized for Task<ID, PRIORITY> {
+        const PRIORITY: u8 = PRIORITY;
+    }
+
+    impl<const ID: u8, const PRIORITY: u8> Id for Task<ID, PRIORITY> {
+        fn id(&self) -> u8 {
+            ID
+        }
+    }
+
+    type Tasks = Link<Task<3, 1>, Link<Task<2, 7>, Link<Task<1, 1>, Chain<Task<0, 3>>>>>;
+
+    #[test]
+    pub fn test_priority_order() {
+        const ORDER: [usize; 4] = priority_order::<Tasks, 4>();
+
+        assert_eq!([2, 0, 1, 3], ORDER);
+    }
+
+    #[test]
+    pub fn test_for_each_prioritized() {
+        let mut tasks: Tasks = Chain::new(Task).append(Task).append(Task).append(Task);
+
+        let mut visited = [0; 4];
+        let mut count = 0;
+        tasks.for_each_prioritized::<dyn Id, _>(|task| {
+            visited[count] = task.id();
+            count += 1;
+        });
+
+        assert_eq!([2, 0, 1, 3], visited);
+    }
+}
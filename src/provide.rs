@@ -0,0 +1,141 @@
+//! Resolving objects from a chain, like a dependency injection container.
+
+use core::{marker::PhantomData, ops::Deref};
+
+use crate::{private, Chain, ChainElement, Here, Link, Selector, There};
+
+/// An object that can construct a `T` from the objects that were appended before it.
+///
+/// `C` is the chain of the preceding objects, or `()` if the provider is the first object of the
+/// chain. Use [`ChainElement::resolve`] to obtain a `T` from a chain.
+pub trait Provide<T, C: ?Sized> {
+    /// Constructs a `T`.
+    fn provide(&self, preceding: &C) -> T;
+}
+
+/// Index of an object of the chain that is resolved by reference.
+pub struct Stored<I>(PhantomData<I>);
+
+/// Index of a [`Provide`] object of the chain that constructs the resolved value.
+pub struct Provided<I>(PhantomData<I>);
+
+/// A value resolved from a chain, either a reference to an object of the chain, or a value
+/// constructed by a provider.
+pub enum Resolved<'a, T> {
+    /// An object of the chain.
+    Stored(&'a T),
+
+    /// A value constructed by a provider.
+    Provided(T),
+}
+
+impl<T: Clone> Resolved<'_, T> {
+    /// Returns the resolved value, cloning it if it is stored in the chain.
+    #[inline]
+    pub fn into_owned(self) -> T {
+        match self {
+            Resolved::Stored(object) => object.clone(),
+            Resolved::Provided(object) => object,
+        }
+    }
+}
+
+impl<T> Deref for Resolved<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        match self {
+            Resolved::Stored(object) => object,
+            Resolved::Provided(object) => object,
+        }
+    }
+}
+
+/// Implemented by chains that contain a `T` object, or a provider that can construct a `T`.
+///
+/// The index type `I` is either [`Stored`] or [`Provided`], wrapping the position of the object
+/// like in [`Selector`]. The compiler infers it if there is a single way to resolve `T`.
+pub trait Resolve<T, I>: ChainElement {
+    /// Returns the stored `T` object, or constructs a `T` using a provider.
+    fn resolve_object(&self) -> Resolved<'_, T>;
+}
+
+impl<T, I, C: Selector<T, I>> Resolve<T, Stored<I>> for C {
+    #[inline]
+    fn resolve_object(&self) -> Resolved<'_, T> {
+        Resolved::Stored(self.select())
+    }
+}
+
+impl<T, I, C: ChainElement + private::ProvidedBy<T, I>> Resolve<T, Provided<I>> for C {
+    #[inline]
+    fn resolve_object(&self) -> Resolved<'_, T> {
+        Resolved::Provided(self.provide_object())
+    }
+}
+
+impl<T, P: Provide<T, ()>> private::ProvidedBy<T, Here> for Chain<P> {
+    #[inline]
+    fn provide_object(&self) -> T {
+        self.object.provide(&())
+    }
+}
+
+impl<T, P: Provide<T, C>, C: ChainElement> private::ProvidedBy<T, Here> for Link<P, C> {
+    #[inline]
+    fn provide_object(&self) -> T {
+        self.object.provide(&self.parent)
+    }
+}
+
+impl<T, I, V, C: private::ProvidedBy<T, I> + ChainElement> private::ProvidedBy<T, There<I>>
+    for Link<V, C>
+{
+    #[inline]
+    fn provide_object(&self) -> T {
+        self.parent.provide_object()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement, Provide, Resolved, Stored};
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Bus(u32);
+
+    struct Display<'a> {
+        bus: &'a Bus,
+        width: u16,
+    }
+
+    struct DisplayProvider {
+        width: u16,
+    }
+
+    impl<'a> Provide<Display<'a>, Chain<&'a Bus>> for DisplayProvider {
+        fn provide(&self, preceding: &Chain<&'a Bus>) -> Display<'a> {
+            Display {
+                bus: preceding.object,
+                width: self.width,
+            }
+        }
+    }
+
+    #[test]
+    pub fn test_resolve() {
+        let bus = Bus(8_000_000);
+        let chain = Chain::new(&bus)
+            .append(DisplayProvider { width: 128 })
+            .append(Bus(1));
+
+        let display = chain.resolve::<Display, _>();
+        assert_eq!(8_000_000, display.bus.0);
+        assert_eq!(128, display.width);
+        assert!(matches!(display, Resolved::Provided(_)));
+
+        let stored = chain.resolve::<Bus, Stored<_>>();
+        assert_eq!(Bus(1), stored.into_owned());
+    }
+}
@@ -0,0 +1,76 @@
+//! Borrowing every object of a chain.
+
+use crate::{Chain, ChainElement, Link};
+
+/// Implemented by chains that can be converted into a chain of references to their objects.
+///
+/// Use [`ChainElement::as_refs`] and [`ChainElement::as_mut_refs`] instead of calling these
+/// methods directly. Chains of references are useful to apply by-value operations, like
+/// [`ChainElement::zip_with`], without consuming the original chain.
+pub trait AsRefs<'a>: ChainElement {
+    /// The chain of shared references.
+    type Refs: ChainElement;
+
+    /// The chain of mutable references.
+    type RefsMut: ChainElement;
+
+    /// Returns a chain of references to every object, in the order they were appended.
+    fn borrow_each(&'a self) -> Self::Refs;
+
+    /// Returns a chain of mutable references to every object, in the order they were appended.
+    fn borrow_each_mut(&'a mut self) -> Self::RefsMut;
+}
+
+impl<'a, V: ?Sized + 'a> AsRefs<'a> for Chain<V> {
+    type Refs = Chain<&'a V>;
+    type RefsMut = Chain<&'a mut V>;
+
+    #[inline]
+    fn borrow_each(&'a self) -> Self::Refs {
+        Chain::new(&self.object)
+    }
+
+    #[inline]
+    fn borrow_each_mut(&'a mut self) -> Self::RefsMut {
+        Chain::new(&mut self.object)
+    }
+}
+
+impl<'a, V: ?Sized + 'a, C: AsRefs<'a> + 'a> AsRefs<'a> for Link<V, C> {
+    type Refs = Link<&'a V, C::Refs>;
+    type RefsMut = Link<&'a mut V, C::RefsMut>;
+
+    #[inline]
+    fn borrow_each(&'a self) -> Self::Refs {
+        Link {
+            parent: self.parent.borrow_each(),
+            object: &self.object,
+        }
+    }
+
+    #[inline]
+    fn borrow_each_mut(&'a mut self) -> Self::RefsMut {
+        Link {
+            parent: self.parent.borrow_each_mut(),
+            object: &mut self.object,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{assert_chain_eq, Chain, ChainElement};
+
+    #[test]
+    pub fn test_as_refs() {
+        let mut chain = Chain::new(1u8).append("two").append(3.0f32);
+
+        assert_chain_eq!(chain.as_refs(), (&1, &"two", &3.0));
+
+        let refs = chain.as_mut_refs();
+        *refs.parent.parent.object += 1;
+        *refs.object *= 2.0;
+
+        assert_chain_eq!(chain, (2, "two", 6.0));
+    }
+}
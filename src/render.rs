@@ -0,0 +1,77 @@
+//! Rendering a frame in multiple passes.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A rendering step that draws onto a target of type `T`, e.g. a frame buffer.
+pub trait RenderPass<T: ?Sized> {
+    /// Renders the pass onto `target`.
+    fn render(&mut self, target: &mut T);
+}
+
+/// A chain of [`RenderPass`] objects that draw onto the same target.
+///
+/// The passes are executed in the order they were appended, and every pass sees the result of the
+/// previous ones. A chain is usually built starting with the background, followed by the content,
+/// and finally the overlays.
+pub trait RenderChain<T: ?Sized>: ChainElement {
+    /// Executes every pass on `target`, in the order they were appended.
+    fn render_all(&mut self, target: &mut T);
+}
+
+impl<T: ?Sized, P: RenderPass<T>> RenderChain<T> for Chain<P> {
+    #[inline]
+    fn render_all(&mut self, target: &mut T) {
+        self.object.render(target);
+    }
+}
+
+impl<T: ?Sized, P: RenderPass<T>, C: RenderChain<T>> RenderChain<T> for Link<P, C> {
+    #[inline]
+    fn render_all(&mut self, target: &mut T) {
+        self.parent.render_all(target);
+        self.object.render(target);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Clear(u8);
+
+    /// Draws a cursor that blinks every frame.
+    struct Cursor {
+        position: usize,
+        visible: bool,
+    }
+
+    impl RenderPass<[u8]> for Clear {
+        fn render(&mut self, target: &mut [u8]) {
+            target.fill(self.0);
+        }
+    }
+
+    impl RenderPass<[u8]> for Cursor {
+        fn render(&mut self, target: &mut [u8]) {
+            if self.visible {
+                target[self.position] = b'_';
+            }
+            self.visible = !self.visible;
+        }
+    }
+
+    #[test]
+    pub fn test_render_all() {
+        let mut passes = Chain::new(Clear(b' ')).append(Cursor {
+            position: 1,
+            visible: true,
+        });
+
+        let mut frame = *b"abcd";
+        passes.render_all(&mut frame[..]);
+        assert_eq!(*b" _  ", frame);
+
+        passes.render_all(&mut frame[..]);
+        assert_eq!(*b"    ", frame);
+    }
+}
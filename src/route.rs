@@ -0,0 +1,182 @@
+//! Dispatching events to the chain objects that accept them.
+
+use crate::{private, Chain, ChainElement, Keep, Link, Matches, Skip};
+
+/// A handler of events of type `E`.
+///
+/// Objects of a [`RouteChain<E>`] must state whether they accept `E` by implementing
+/// [`Matches<E>`]. The objects that accept `E`, i.e. whose decision is [`Keep`], must also
+/// implement this trait.
+pub trait Accept<E> {
+    /// Handles the event.
+    fn accept(&mut self, event: &E);
+}
+
+impl<V: Accept<E>, E> private::RouteStep<V, E> for Keep {
+    #[inline]
+    fn route_to(object: &mut V, event: &E) {
+        object.accept(event);
+    }
+}
+
+impl<V, E> private::RouteStep<V, E> for Skip {
+    #[inline]
+    fn route_to(_object: &mut V, _event: &E) {}
+}
+
+/// A chain whose objects all state whether they accept events of type `E`.
+///
+/// Whether an object receives an event is decided by its type, at compile time, so routing an
+/// event costs nothing for the objects that skip it.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Accept, Chain, ChainElement, Keep, Matches, RouteChain, Skip};
+///
+/// struct ButtonPressed(u8);
+///
+/// struct Display {
+///     last_button: u8,
+/// }
+///
+/// struct Logger {
+///     lines: u32,
+/// }
+///
+/// impl Matches<ButtonPressed> for Display {
+///     type Decision = Keep;
+/// }
+///
+/// impl Accept<ButtonPressed> for Display {
+///     fn accept(&mut self, event: &ButtonPressed) {
+///         self.last_button = event.0;
+///     }
+/// }
+///
+/// impl Matches<ButtonPressed> for Logger {
+///     type Decision = Skip;
+/// }
+///
+/// let mut handlers = Chain::new(Display { last_button: 0 }).append(Logger { lines: 0 });
+///
+/// handlers.route(&ButtonPressed(3));
+///
+/// assert_eq!(3, handlers.parent.object.last_button);
+/// ```
+pub trait RouteChain<E>: ChainElement {
+    /// Passes `event` to every object that accepts it, in the order they were appended.
+    fn route(&mut self, event: &E);
+}
+
+impl<V, E> RouteChain<E> for Chain<V>
+where
+    V: Matches<E>,
+    V::Decision: private::RouteStep<V, E>,
+{
+    #[inline]
+    fn route(&mut self, event: &E) {
+        <V::Decision as private::RouteStep<V, E>>::route_to(&mut self.object, event);
+    }
+}
+
+impl<V, C, E> RouteChain<E> for Link<V, C>
+where
+    V: Matches<E>,
+    V::Decision: private::RouteStep<V, E>,
+    C: RouteChain<E>,
+{
+    #[inline]
+    fn route(&mut self, event: &E) {
+        self.parent.route(event);
+        <V::Decision as private::RouteStep<V, E>>::route_to(&mut self.object, event);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Tick;
+    struct Key(char);
+
+    #[derive(Default)]
+    struct Clock {
+        ticks: u32,
+    }
+
+    #[derive(Default)]
+    struct Keyboard {
+        last: Option<char>,
+    }
+
+    #[derive(Default)]
+    struct Terminal {
+        ticks: u32,
+        keys: u32,
+    }
+
+    impl Matches<Tick> for Clock {
+        type Decision = Keep;
+    }
+
+    impl Accept<Tick> for Clock {
+        fn accept(&mut self, _event: &Tick) {
+            self.ticks += 1;
+        }
+    }
+
+    impl Matches<Key> for Clock {
+        type Decision = Skip;
+    }
+
+    impl Matches<Tick> for Keyboard {
+        type Decision = Skip;
+    }
+
+    impl Matches<Key> for Keyboard {
+        type Decision = Keep;
+    }
+
+    impl Accept<Key> for Keyboard {
+        fn accept(&mut self, event: &Key) {
+            self.last = Some(event.0);
+        }
+    }
+
+    impl Matches<Tick> for Terminal {
+        type Decision = Keep;
+    }
+
+    impl Accept<Tick> for Terminal {
+        fn accept(&mut self, _event: &Tick) {
+            self.ticks += 1;
+        }
+    }
+
+    impl Matches<Key> for Terminal {
+        type Decision = Keep;
+    }
+
+    impl Accept<Key> for Terminal {
+        fn accept(&mut self, _event: &Key) {
+            self.keys += 1;
+        }
+    }
+
+    #[test]
+    pub fn test_route() {
+        let mut handlers = Chain::new(Clock::default())
+            .append(Keyboard::default())
+            .append(Terminal::default());
+
+        handlers.route(&Tick);
+        handlers.route(&Tick);
+        handlers.route(&Key('a'));
+
+        assert_eq!(2, handlers.parent.parent.object.ticks);
+        assert_eq!(Some('a'), handlers.parent.object.last);
+        assert_eq!(2, handlers.object.ticks);
+        assert_eq!(1, handlers.object.keys);
+    }
+}
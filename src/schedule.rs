@@ -0,0 +1,185 @@
+//! Cooperative scheduling of a chain of tasks.
+
+use core::ops::Add;
+
+use crate::{Chain, ChainElement, Link};
+
+/// A task that is polled periodically by a [`TaskChain`].
+///
+/// `T` is the type of the time stamps and durations, e.g. a `u64` tick count. The time stamps must
+/// not wrap around: the time of the next poll is computed as `now + duration`, which panics on
+/// overflow in debug builds and wraps in release builds. Use a type that is wide enough for the
+/// lifetime of the device, e.g. a `u64` instead of a `u32` tick counter.
+pub trait Task<T> {
+    /// Runs the task, and returns the time until the task should be polled again.
+    fn poll(&mut self, now: T) -> T;
+}
+
+/// A [`Task`] along with the time it should be polled next.
+///
+/// The objects of a [`TaskChain`] are `Scheduled` tasks.
+pub struct Scheduled<K, T> {
+    /// The scheduled task.
+    pub task: K,
+
+    next_poll: Option<T>,
+}
+
+impl<K, T> Scheduled<K, T> {
+    /// Schedules `task` to be polled as soon as possible.
+    #[inline]
+    pub const fn new(task: K) -> Self {
+        Self {
+            task,
+            next_poll: None,
+        }
+    }
+
+    /// Schedules `task` to be polled first at `time`.
+    #[inline]
+    pub const fn at(task: K, time: T) -> Self {
+        Self {
+            task,
+            next_poll: Some(time),
+        }
+    }
+}
+
+impl<K, T> Scheduled<K, T>
+where
+    K: Task<T>,
+    T: Copy + Ord + Add<Output = T>,
+{
+    /// Polls the task if it is due, and returns the time it should be polled next.
+    #[inline]
+    fn run_if_due(&mut self, now: T) -> T {
+        match self.next_poll {
+            Some(next_poll) if next_poll > now => next_poll,
+            _ => {
+                let next_poll = now + self.task.poll(now);
+                self.next_poll = Some(next_poll);
+                next_poll
+            }
+        }
+    }
+}
+
+/// A chain of [`Scheduled`] tasks.
+///
+/// Tasks are polled in the order they were appended, so tasks with a higher priority should be
+/// appended first. There are no separate task priorities. Like with [`Task`], the time stamps must
+/// not wrap around.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, Scheduled, Task, TaskChain};
+///
+/// struct Blink(bool);
+/// struct Report(u32);
+///
+/// impl Task<u64> for Blink {
+///     fn poll(&mut self, _now: u64) -> u64 {
+///         self.0 = !self.0;
+///         500
+///     }
+/// }
+///
+/// impl Task<u64> for Report {
+///     fn poll(&mut self, _now: u64) -> u64 {
+///         self.0 += 1;
+///         1000
+///     }
+/// }
+///
+/// let mut tasks = Chain::new(Scheduled::new(Blink(false))).append(Scheduled::new(Report(0)));
+///
+/// assert_eq!(500, tasks.run_once(0));
+/// assert_eq!(1000, tasks.run_once(500));
+/// assert_eq!(1500, tasks.run_once(1000));
+/// assert_eq!(2, tasks.object.task.0);
+/// ```
+pub trait TaskChain<T>: ChainElement {
+    /// Polls every task that is due at `now`.
+    ///
+    /// Returns the earliest time at which a task should be polled again.
+    fn run_once(&mut self, now: T) -> T;
+}
+
+impl<K, T> TaskChain<T> for Chain<Scheduled<K, T>>
+where
+    K: Task<T>,
+    T: Copy + Ord + Add<Output = T>,
+{
+    #[inline]
+    fn run_once(&mut self, now: T) -> T {
+        self.object.run_if_due(now)
+    }
+}
+
+impl<K, T, C> TaskChain<T> for Link<Scheduled<K, T>, C>
+where
+    K: Task<T>,
+    T: Copy + Ord + Add<Output = T>,
+    C: TaskChain<T>,
+{
+    #[inline]
+    fn run_once(&mut self, now: T) -> T {
+        let parent = self.parent.run_once(now);
+        parent.min(self.object.run_if_due(now))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Periodic {
+        period: u32,
+        polls: [u32; 4],
+        count: usize,
+    }
+
+    impl Periodic {
+        fn new(period: u32) -> Self {
+            Self {
+                period,
+                polls: [0; 4],
+                count: 0,
+            }
+        }
+    }
+
+    impl Task<u32> for Periodic {
+        fn poll(&mut self, now: u32) -> u32 {
+            self.polls[self.count] = now;
+            self.count += 1;
+            self.period
+        }
+    }
+
+    #[test]
+    pub fn test_run_once() {
+        let mut tasks = Chain::new(Scheduled::new(Periodic::new(10)))
+            .append(Scheduled::at(Periodic::new(3), 5));
+
+        assert_eq!(5, tasks.run_once(0));
+        assert_eq!(8, tasks.run_once(5));
+        assert_eq!(10, tasks.run_once(8));
+        assert_eq!(11, tasks.run_once(10));
+
+        assert_eq!([0, 10], tasks.parent.object.task.polls[..2]);
+        assert_eq!([5, 8], tasks.object.task.polls[..2]);
+        assert_eq!(2, tasks.object.task.count);
+    }
+
+    #[test]
+    pub fn test_late_poll() {
+        let mut tasks = Chain::new(Scheduled::new(Periodic::new(10)));
+
+        assert_eq!(10, tasks.run_once(0));
+        assert_eq!(10, tasks.run_once(4));
+        assert_eq!(35, tasks.run_once(25));
+        assert_eq!(2, tasks.object.task.count);
+    }
+}
@@ -0,0 +1,180 @@
+//! Reordering the objects of a chain by their types.
+
+use crate::{private, Chain, ChainElement, Here, Link, There};
+
+/// Implemented by chains that contain an object of type `T`, which can be removed from the chain.
+///
+/// Like with [`Selector`], the index `I` is inferred if the chain contains a single `T` object.
+/// The remaining objects keep their order. If `T` was the only object, the remainder is `()`.
+///
+/// [`Selector`]: crate::Selector
+pub trait Pluck<T, I>: ChainElement {
+    /// The chain of the remaining objects, or `()`.
+    type Remainder;
+
+    /// Removes the object of type `T` from the chain.
+    fn pluck_object(self) -> (T, Self::Remainder);
+}
+
+impl<T> Pluck<T, Here> for Chain<T> {
+    type Remainder = ();
+
+    #[inline]
+    fn pluck_object(self) -> (T, ()) {
+        (self.object, ())
+    }
+}
+
+impl<T, C: ChainElement> Pluck<T, Here> for Link<T, C> {
+    type Remainder = C;
+
+    #[inline]
+    fn pluck_object(self) -> (T, C) {
+        (self.object, self.parent)
+    }
+}
+
+impl<T, I, V, C> Pluck<T, There<I>> for Link<V, C>
+where
+    C: Pluck<T, I>,
+    C::Remainder: private::Push<V>,
+{
+    type Remainder = <C::Remainder as private::Push<V>>::Pushed;
+
+    #[inline]
+    fn pluck_object(self) -> (T, Self::Remainder) {
+        let (object, remainder) = self.parent.pluck_object();
+        (object, private::Push::push(remainder, self.object))
+    }
+}
+
+/// Implemented by chains that contain every object type of the chain `Target`, in any order.
+///
+/// The objects are moved into a chain of type `Target`, and the objects that are not part of
+/// `Target` are returned as the remainder, or `()` if there are none. The indices `I` are inferred
+/// by the compiler, as long as every object type of `Target` is unique in the chain.
+///
+/// This allows functions to accept chains that contain at least some object types, regardless of
+/// their order:
+///
+/// ```rust
+/// use object_chain::{chain, Chain, ChainElement, Link, Sculpt};
+///
+/// struct Uart(u32);
+/// struct Spi(u32);
+/// struct Led(bool);
+///
+/// fn configure<C, I>(resources: C) -> u32
+/// where
+///     C: Sculpt<chain![Spi, Uart], I>,
+/// {
+///     let (needed, _rest) = resources.sculpt_into();
+///     needed.parent.object.0 + needed.object.0
+/// }
+///
+/// let resources = Chain::new(Uart(115_200)).append(Led(false)).append(Spi(1_000_000));
+/// assert_eq!(1_115_200, configure(resources));
+/// ```
+///
+/// [`ChainElement::extract`] does the same, but takes the target chain as a type parameter. Use
+/// [`ChainElement::extract_ref`] and [`ChainElement::extract_mut`] to extract references to the
+/// objects without consuming the chain:
+///
+/// ```rust
+/// use object_chain::{chain, Chain, ChainElement, Link};
+///
+/// struct Uart(u32);
+/// struct Spi(u32);
+/// struct Led(bool);
+///
+/// fn blink(led: &mut Led) {
+///     led.0 = !led.0;
+/// }
+///
+/// let mut resources = Chain::new(Uart(115_200)).append(Led(false)).append(Spi(1_000_000));
+///
+/// let view = resources.extract_mut::<chain![Led, Uart], _>();
+/// blink(view.parent.object);
+/// view.object.0 = 9600;
+///
+/// let (bus, rest) = resources.extract::<chain![Spi], _>();
+/// assert_eq!(1_000_000, bus.object.0);
+/// assert!(rest.object.0);
+/// assert_eq!(9600, rest.parent.object.0);
+/// ```
+pub trait Sculpt<Target, I>: ChainElement {
+    /// The chain of the objects that are not part of `Target`, or `()`.
+    type Remainder;
+
+    /// Moves the objects into a chain of type `Target`.
+    fn sculpt_into(self) -> (Target, Self::Remainder);
+}
+
+impl<T, I, S: Pluck<T, I>> Sculpt<Chain<T>, I> for S {
+    type Remainder = S::Remainder;
+
+    #[inline]
+    fn sculpt_into(self) -> (Chain<T>, Self::Remainder) {
+        let (object, remainder) = self.pluck_object();
+        (Chain::new(object), remainder)
+    }
+}
+
+impl<T, TC, I, J, S> Sculpt<Link<T, TC>, (I, J)> for S
+where
+    TC: ChainElement,
+    S: Pluck<T, I>,
+    S::Remainder: Sculpt<TC, J>,
+{
+    type Remainder = <S::Remainder as Sculpt<TC, J>>::Remainder;
+
+    #[inline]
+    fn sculpt_into(self) -> (Link<T, TC>, Self::Remainder) {
+        let (object, remainder) = self.pluck_object();
+        let (parent, remainder) = remainder.sculpt_into();
+        (Link { parent, object }, remainder)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{assert_chain_eq, chain, Chain, ChainElement, Link};
+
+    #[test]
+    pub fn test_pluck() {
+        let chain = Chain::new(1u8).append(2u16).append(3u32);
+
+        let (object, rest) = chain.pluck::<u16, _>();
+        assert_eq!(2, object);
+        assert_chain_eq!(rest, (1, 3));
+
+        let (object, ()) = Chain::new(5u8).pluck::<u8, _>();
+        assert_eq!(5, object);
+    }
+
+    #[test]
+    pub fn test_extract() {
+        let mut chain = Chain::new(1u8).append(2u16).append(3u32);
+
+        let refs = chain.extract_ref::<chain![u32, u8], _>();
+        assert_chain_eq!(refs, (&3, &1));
+
+        *chain.extract_mut::<chain![u16], _>().object += 5;
+
+        let (subset, rest) = chain.extract::<chain![u16, u8], _>();
+        assert_chain_eq!(subset, (7, 1));
+        assert_chain_eq!(rest, (3,));
+    }
+
+    #[test]
+    pub fn test_sculpt() {
+        let chain = Chain::new(1u8).append(2u16).append(3u32).append('c');
+
+        let (sculpted, rest): (chain![u32, u8, char], _) = chain.sculpt();
+        assert_chain_eq!(sculpted, (3, 1, 'c'));
+        assert_chain_eq!(rest, (2,));
+
+        let (permuted, ()): (chain![u16, u8], _) = Chain::new(1u8).append(2u16).sculpt();
+        assert_chain_eq!(permuted, (2, 1));
+    }
+}
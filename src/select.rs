@@ -0,0 +1,272 @@
+//! Accessing chain objects by their type.
+
+use core::{
+    any::{Any, TypeId},
+    marker::PhantomData,
+};
+
+use crate::{Chain, ChainElement, Link};
+
+/// Index of an object that is stored in the current chain element.
+///
+/// For a whole chain, this is the object that was appended last.
+pub struct Here;
+
+/// Index of an object that is stored in the parent of the current chain element.
+///
+/// `There<Here>` is the object that was appended second to last, `There<There<Here>>` is the one
+/// before it, and so on.
+pub struct There<I>(PhantomData<I>);
+
+/// Implemented by chains that contain an object of type `T`.
+///
+/// The index type `I` describes where the object is located in the chain. You don't need to
+/// specify it, the compiler infers it for you as long as the chain contains a single `T` object.
+///
+/// If the chain contains more than one `T` object, the index is ambiguous and must be specified
+/// using [`Here`] and [`There`]:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, Here, There};
+///
+/// let chain = Chain::new(1u8).append(2u16).append(3u8);
+///
+/// assert_eq!(3, *chain.get_of::<u8, Here>());
+/// assert_eq!(1, *chain.get_of::<u8, There<There<Here>>>());
+///
+/// // Unique types are still inferred.
+/// assert_eq!(2, *chain.get_of::<u16, _>());
+/// ```
+pub trait Selector<T, I>: ChainElement {
+    /// The position of the object of type `T`, in the order the objects were appended.
+    ///
+    /// See [`position_of`] for a more convenient way to access it.
+    const POSITION: usize;
+
+    /// Returns a reference to the object of type `T`.
+    fn select(&self) -> &T;
+
+    /// Returns a mutable reference to the object of type `T`.
+    fn select_mut(&mut self) -> &mut T;
+}
+
+impl<T> Selector<T, Here> for Chain<T> {
+    const POSITION: usize = 0;
+
+    #[inline]
+    fn select(&self) -> &T {
+        &self.object
+    }
+
+    #[inline]
+    fn select_mut(&mut self) -> &mut T {
+        &mut self.object
+    }
+}
+
+impl<T, C: ChainElement> Selector<T, Here> for Link<T, C> {
+    const POSITION: usize = C::LEN;
+
+    #[inline]
+    fn select(&self) -> &T {
+        &self.object
+    }
+
+    #[inline]
+    fn select_mut(&mut self) -> &mut T {
+        &mut self.object
+    }
+}
+
+impl<T, V, C, I> Selector<T, There<I>> for Link<V, C>
+where
+    C: Selector<T, I>,
+{
+    const POSITION: usize = C::POSITION;
+
+    #[inline]
+    fn select(&self) -> &T {
+        self.parent.select()
+    }
+
+    #[inline]
+    fn select_mut(&mut self) -> &mut T {
+        self.parent.select_mut()
+    }
+}
+
+/// Returns the position of the object of type `T` in the chain `C`, in the order the objects were
+/// appended.
+///
+/// The position is evaluated at compile time, so it can be used to drive index-based APIs, like
+/// [`static_dispatch!`], by types instead of magic numbers.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{chain, position_of, Chain, Link};
+///
+/// struct Uart;
+/// struct Spi;
+///
+/// type Drivers = chain![Uart, Spi, u8];
+///
+/// const SPI: usize = position_of::<Drivers, Spi, _>();
+///
+/// assert_eq!(1, SPI);
+/// assert_eq!(0, position_of::<Drivers, Uart, _>());
+/// ```
+///
+/// [`static_dispatch!`]: crate::static_dispatch
+#[inline]
+pub const fn position_of<C: Selector<T, I>, T, I>() -> usize {
+    C::POSITION
+}
+
+/// Implemented by chains of `'static` objects to search objects by their type at runtime.
+///
+/// Unlike [`Selector`], this allows the chain to contain any number of objects of the searched
+/// type. Use [`ChainElement::last_of`] and [`ChainElement::count_of`] instead of calling these
+/// methods directly, or [`count_of`](crate::count_of) to count objects at compile time.
+///
+/// These searches happen at runtime, see [type-level limitations](crate#type-level-limitations).
+pub trait Occurrences: ChainElement {
+    /// Returns the last appended object whose type has the given [`TypeId`].
+    fn find_type(&self, id: TypeId) -> Option<&dyn Any>;
+
+    /// Returns the last appended object whose type has the given [`TypeId`].
+    fn find_type_mut(&mut self, id: TypeId) -> Option<&mut dyn Any>;
+
+    /// Returns how many objects have a type with the given [`TypeId`].
+    fn count_type(id: TypeId) -> usize;
+}
+
+impl<V: 'static> Occurrences for Chain<V> {
+    #[inline]
+    fn find_type(&self, id: TypeId) -> Option<&dyn Any> {
+        if TypeId::of::<V>() == id {
+            Some(&self.object)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn find_type_mut(&mut self, id: TypeId) -> Option<&mut dyn Any> {
+        if TypeId::of::<V>() == id {
+            Some(&mut self.object)
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn count_type(id: TypeId) -> usize {
+        (TypeId::of::<V>() == id) as usize
+    }
+}
+
+impl<V: 'static, C: Occurrences> Occurrences for Link<V, C> {
+    #[inline]
+    fn find_type(&self, id: TypeId) -> Option<&dyn Any> {
+        if TypeId::of::<V>() == id {
+            Some(&self.object)
+        } else {
+            self.parent.find_type(id)
+        }
+    }
+
+    #[inline]
+    fn find_type_mut(&mut self, id: TypeId) -> Option<&mut dyn Any> {
+        if TypeId::of::<V>() == id {
+            Some(&mut self.object)
+        } else {
+            self.parent.find_type_mut(id)
+        }
+    }
+
+    #[inline]
+    fn count_type(id: TypeId) -> usize {
+        (TypeId::of::<V>() == id) as usize + C::count_type(id)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{position_of, Chain, ChainElement, ChainError, Here, Link, There};
+
+    #[test]
+    pub fn test_get_by_type() {
+        let mut chain = Chain::new(1u8).append(2u16).append(3u32);
+
+        assert_eq!(1, *chain.get_of::<u8, _>());
+        assert_eq!(2, *chain.get_of::<u16, _>());
+        assert_eq!(3, *chain.get_of::<u32, _>());
+
+        *chain.get_of_mut::<u16, _>() = 5;
+        assert_eq!(5, chain.parent.object);
+    }
+
+    #[test]
+    pub fn test_position_of() {
+        type Objects = Link<u32, Link<u8, Link<u16, Chain<u8>>>>;
+
+        const POSITIONS: [usize; 3] = [
+            position_of::<Objects, u16, _>(),
+            position_of::<Objects, u32, _>(),
+            position_of::<Objects, u8, There<Here>>(),
+        ];
+
+        assert_eq!([1, 3, 2], POSITIONS);
+        assert_eq!(0, position_of::<Chain<u8>, u8, _>());
+    }
+
+    #[test]
+    pub fn test_update_by_type() {
+        struct Backlight {
+            level: u8,
+        }
+
+        let mut chain = Chain::new(1u8).append(Backlight { level: 0 }).append(3u32);
+
+        chain.update_of::<Backlight, _>(|backlight| backlight.level = 5);
+        assert_eq!(5, chain.parent.object.level);
+    }
+
+    #[test]
+    pub fn test_duplicate_types() {
+        let mut chain = Chain::new(1u8).append(2u16).append(3u8);
+
+        *chain.get_of_mut::<u8, There<There<Here>>>() = 4;
+        chain.update_of::<u8, Here>(|object| *object += 1);
+
+        assert_eq!(4, chain.parent.parent.object);
+        assert_eq!(4, chain.object);
+    }
+
+    #[test]
+    pub fn test_last_of() {
+        let mut chain = Chain::new(1u8).append(2u16).append(3u8).append(4u32);
+
+        assert_eq!(Some(&3), chain.last_of::<u8>());
+        assert_eq!(None, chain.last_of::<u64>());
+        assert_eq!(Ok(&4), chain.try_last_of::<u32>());
+        assert_eq!(
+            Err(ChainError::TypeNotFound),
+            chain.try_last_of_mut::<u64>()
+        );
+
+        *chain.last_of_mut::<u8>().unwrap() = 5;
+        assert_eq!(5, chain.parent.object);
+        assert_eq!(1, chain.parent.parent.parent.object);
+    }
+
+    #[test]
+    pub fn test_count_of() {
+        let chain = Chain::new(1u8).append(2u16).append(3u8);
+
+        assert_eq!(2, chain.count_of::<u8>());
+        assert_eq!(1, chain.count_of::<u16>());
+        assert_eq!(0, chain.count_of::<u32>());
+    }
+}
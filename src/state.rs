@@ -0,0 +1,160 @@
+//! Driving multiple state machines with a shared event type.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A state machine that advances by handling events of type `E`.
+pub trait StateMachine<E: ?Sized> {
+    /// Advances the state machine by handling `event`.
+    fn handle(&mut self, event: &E);
+
+    /// Returns whether the state machine has reached a terminal state.
+    fn is_terminal(&self) -> bool;
+}
+
+/// The aggregate status of a chain of state machines.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MachineStatus {
+    /// The number of state machines that have not reached a terminal state.
+    pub running: usize,
+
+    /// The number of state machines in a terminal state.
+    pub terminal: usize,
+}
+
+impl MachineStatus {
+    /// Returns whether every state machine has reached a terminal state.
+    #[inline]
+    pub const fn is_finished(&self) -> bool {
+        self.running == 0
+    }
+
+    #[inline]
+    fn record(mut self, terminal: bool) -> Self {
+        if terminal {
+            self.terminal += 1;
+        } else {
+            self.running += 1;
+        }
+        self
+    }
+}
+
+/// A chain of [`StateMachine`] objects that handle the same event type.
+pub trait StateMachineChain<E: ?Sized>: ChainElement {
+    /// Passes `event` to every state machine that has not reached a terminal state, in the order
+    /// they were appended.
+    ///
+    /// Returns the status of the state machines after handling the event.
+    fn step_all(&mut self, event: &E) -> MachineStatus;
+
+    /// Returns the status of the state machines.
+    fn status(&self) -> MachineStatus;
+}
+
+impl<E: ?Sized, M: StateMachine<E>> StateMachineChain<E> for Chain<M> {
+    #[inline]
+    fn step_all(&mut self, event: &E) -> MachineStatus {
+        if !self.object.is_terminal() {
+            self.object.handle(event);
+        }
+        MachineStatus::default().record(self.object.is_terminal())
+    }
+
+    #[inline]
+    fn status(&self) -> MachineStatus {
+        MachineStatus::default().record(self.object.is_terminal())
+    }
+}
+
+impl<E: ?Sized, M: StateMachine<E>, C: StateMachineChain<E>> StateMachineChain<E> for Link<M, C> {
+    #[inline]
+    fn step_all(&mut self, event: &E) -> MachineStatus {
+        let status = self.parent.step_all(event);
+        if !self.object.is_terminal() {
+            self.object.handle(event);
+        }
+        status.record(self.object.is_terminal())
+    }
+
+    #[inline]
+    fn status(&self) -> MachineStatus {
+        self.parent.status().record(self.object.is_terminal())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Waits for a given number of bytes.
+    struct Counter {
+        remaining: usize,
+        handled: usize,
+    }
+
+    /// Waits for a specific byte.
+    struct Matcher {
+        expected: u8,
+        matched: bool,
+    }
+
+    impl StateMachine<u8> for Counter {
+        fn handle(&mut self, _event: &u8) {
+            self.remaining -= 1;
+            self.handled += 1;
+        }
+
+        fn is_terminal(&self) -> bool {
+            self.remaining == 0
+        }
+    }
+
+    impl StateMachine<u8> for Matcher {
+        fn handle(&mut self, event: &u8) {
+            self.matched = *event == self.expected;
+        }
+
+        fn is_terminal(&self) -> bool {
+            self.matched
+        }
+    }
+
+    #[test]
+    pub fn test_step_all() {
+        let mut machines = Chain::new(Counter {
+            remaining: 2,
+            handled: 0,
+        })
+        .append(Matcher {
+            expected: b'\n',
+            matched: false,
+        });
+
+        assert_eq!(
+            MachineStatus {
+                running: 2,
+                terminal: 0
+            },
+            machines.status()
+        );
+
+        assert_eq!(
+            MachineStatus {
+                running: 2,
+                terminal: 0
+            },
+            machines.step_all(&b'a')
+        );
+        assert_eq!(
+            MachineStatus {
+                running: 1,
+                terminal: 1
+            },
+            machines.step_all(&b'b')
+        );
+
+        let status = machines.step_all(&b'\n');
+        assert!(status.is_finished());
+        assert_eq!(2, machines.parent.object.handled);
+    }
+}
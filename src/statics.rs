@@ -0,0 +1,144 @@
+//! Building chains in const contexts.
+
+/// Creates a chain from a list of objects, in the order they should be appended.
+///
+/// Unlike [`ChainElement::append`], this macro can be used in const contexts, e.g. to initialize a
+/// `const` or a `static`.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{const_chain, Chain, ChainElement, Link};
+///
+/// const CHAIN: Link<u16, Chain<u8>> = const_chain![1u8, 2u16];
+///
+/// assert_eq!(2, CHAIN.len());
+/// assert_eq!(1, CHAIN.parent.object);
+/// ```
+///
+/// [`ChainElement::append`]: crate::ChainElement::append
+#[macro_export]
+macro_rules! const_chain {
+    (@build $chain:expr;) => {
+        $chain
+    };
+    (@build $chain:expr; $object:expr $(, $rest:expr)*) => {
+        $crate::const_chain!(@build $crate::Link { parent: $chain, object: $object }; $($rest),*)
+    };
+    [$first:expr $(, $rest:expr)* $(,)?] => {
+        $crate::const_chain!(@build $crate::Chain::new($first); $($rest),*)
+    };
+}
+
+/// Implementation of `static_chain!`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chain_type {
+    (@build $chain:ty;) => {
+        $chain
+    };
+    (@build $chain:ty; $object:ty $(, $rest:ty)*) => {
+        $crate::__chain_type!(@build $crate::Link<$object, $chain>; $($rest),*)
+    };
+    ($first:ty $(, $rest:ty)*) => {
+        $crate::__chain_type!(@build $crate::Chain<$first>; $($rest),*)
+    };
+}
+
+/// Declares a `static` chain that is constructed at compile time.
+///
+/// The types and the initializers of the objects are listed in the order they should be appended.
+/// The initializers must be constant expressions. A static chain lives for `'static` without any
+/// heap allocation, and can be accessed from anywhere in the program.
+///
+/// Statics can't be mutated without interior mutability. To get mutable access, wrap the chain
+/// into a type which has a `const fn new(chain)` constructor, like [`SharedChain`] when the
+/// `critical-section` feature is enabled:
+///
+/// ```rust,ignore
+/// static_chain! {
+///     static DEVICES: SharedChain<[Uart, Spi]> = [Uart::new(), Spi::new()];
+/// }
+/// ```
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{static_chain, ChainElement};
+///
+/// struct Uart {
+///     baud: u32,
+/// }
+///
+/// struct Spi {
+///     frequency: u32,
+/// }
+///
+/// static_chain! {
+///     /// The drivers of the board.
+///     pub static DRIVERS: [Uart, Spi] = [Uart { baud: 115_200 }, Spi { frequency: 1_000_000 }];
+/// }
+///
+/// assert_eq!(2, DRIVERS.len());
+/// assert_eq!(115_200, DRIVERS.get_of::<Uart, _>().baud);
+/// assert_eq!(1_000_000, DRIVERS.object.frequency);
+/// ```
+///
+/// [`SharedChain`]: https://docs.rs/object-chain/latest/object_chain/struct.SharedChain.html
+#[macro_export]
+macro_rules! static_chain {
+    (
+        $(#[$attr:meta])*
+        $vis:vis static $name:ident: $wrapper:ident<[$($types:ty),+ $(,)?]> = [$($objects:expr),+ $(,)?];
+    ) => {
+        $(#[$attr])*
+        $vis static $name: $wrapper<$crate::__chain_type!($($types),+)> =
+            $wrapper::new($crate::const_chain![$($objects),+]);
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis static $name:ident: [$($types:ty),+ $(,)?] = [$($objects:expr),+ $(,)?];
+    ) => {
+        $(#[$attr])*
+        $vis static $name: $crate::__chain_type!($($types),+) = $crate::const_chain![$($objects),+];
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement, Link};
+
+    struct Wrapper<C>(C);
+
+    impl<C> Wrapper<C> {
+        const fn new(chain: C) -> Self {
+            Self(chain)
+        }
+    }
+
+    static_chain! {
+        static PLAIN: [u8, u16, u32] = [1, 2, 3];
+    }
+
+    static_chain! {
+        static WRAPPED: Wrapper<[u8, u16]> = [4, 5];
+    }
+
+    #[test]
+    pub fn test_const_chain() {
+        const CHAIN: Link<u32, Link<u16, Chain<u8>>> = const_chain![1, 2, 3];
+
+        assert_eq!(3, CHAIN.len());
+        assert_eq!(1, *CHAIN.at::<0>());
+        assert_eq!(3, *CHAIN.at::<2>());
+    }
+
+    #[test]
+    pub fn test_static_chain() {
+        assert_eq!(3, PLAIN.len());
+        assert_eq!(2, *PLAIN.at::<1>());
+
+        assert_eq!(2, WRAPPED.0.len());
+        assert_eq!(4, WRAPPED.0.parent.object);
+    }
+}
@@ -0,0 +1,231 @@
+//! Converting between structs and chains.
+
+use crate::ChainElement;
+
+/// A struct that can be converted into a chain of its fields, and back.
+///
+/// The fields are appended in the order they are declared. With the `derive` feature enabled,
+/// this trait can be implemented using `#[derive(Chain)]`, which also implements
+/// `From<Self::AsChain>` for the struct.
+///
+/// # Example:
+///
+/// ```rust
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use object_chain::{Chain, ChainElement, ChainStruct};
+///
+/// struct Spi(u32);
+/// struct Uart(u32);
+///
+/// #[derive(Chain)]
+/// struct Board {
+///     spi: Spi,
+///     uart: Uart,
+/// }
+///
+/// let chain = Board {
+///     spi: Spi(1_000_000),
+///     uart: Uart(115_200),
+/// }
+/// .into_chain();
+/// assert_eq!(115_200, chain.get_of::<Uart, _>().0);
+///
+/// let board = Board::from(chain);
+/// assert_eq!(1_000_000, board.spi.0);
+/// # }
+/// ```
+pub trait ChainStruct: Sized {
+    /// The chain of the fields.
+    type AsChain: ChainElement;
+
+    /// Converts the struct into a chain of its fields.
+    fn into_chain(self) -> Self::AsChain;
+
+    /// Creates the struct from a chain of its fields.
+    fn from_chain(chain: Self::AsChain) -> Self;
+}
+
+/// Implementation of `chain_struct!`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __chain_struct {
+    (@from $chain:ident; $first:ident $($rest:ident)*) => {{
+        let $crate::__chain_struct!(@pattern [$crate::Chain { object: $first }] $($rest)*) = $chain;
+        Self { $first $(, $rest)* }
+    }};
+    (@pattern [$($pattern:tt)*]) => {
+        $($pattern)*
+    };
+    (@pattern [$($pattern:tt)*] $field:ident $($rest:ident)*) => {
+        $crate::__chain_struct!(@pattern [$crate::Link { parent: $($pattern)*, object: $field }] $($rest)*)
+    };
+    (
+        [$(#[$attr:meta])*] $vis:vis $name:ident $chain:ty;
+        [$($done:tt)*] $position:ty;
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($done)*
+        }
+    };
+    (
+        [$(#[$attr:meta])*] $vis:vis $name:ident $chain:ty;
+        [$($done:tt)*] $position:ty;
+        $(#[$field_attr:meta])* $field_vis:vis $field:ident $(, $($rest:tt)*)?
+    ) => {
+        $crate::__chain_struct!(
+            [$(#[$attr])*] $vis $name $chain;
+            [
+                $($done)*
+                $(#[$field_attr])*
+                $field_vis $field: <$chain as $crate::At<$position>>::Output,
+            ] $crate::Succ<$position>;
+            $($($rest)*)?
+        );
+    };
+}
+
+/// Declares a struct with a named field for every object of an existing chain type.
+///
+/// The field types are the types of the objects, in the order they were appended. The chain type
+/// can be any type, e.g. an alias for a chain that is composed by library code. The macro
+/// implements [`ChainStruct`] for the struct, converting losslessly in both directions. Specifying
+/// a different number of fields than the length of the chain is a compile error.
+///
+/// Unlike `#[derive(Chain)]`, this macro does not implement `From<Chain>`, because the chain type
+/// may be a projection that the compiler can't prove to be different from the struct.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{chain_struct, Chain, Concat, ChainElement, ChainStruct};
+///
+/// pub struct Spi(u32);
+/// pub struct Uart(u32);
+///
+/// // The chain type is composed from other chain types.
+/// type Drivers = <Chain<Spi> as Concat<Chain<Uart>>>::Output;
+///
+/// chain_struct! {
+///     /// The drivers of the board.
+///     pub struct Board(Drivers) {
+///         pub spi,
+///         pub uart,
+///     }
+/// }
+///
+/// let board = Board::from_chain(Chain::new(Spi(1_000_000)).append(Uart(115_200)));
+/// assert_eq!(115_200, board.uart.0);
+///
+/// let chain = board.into_chain();
+/// assert_eq!(1_000_000, chain.get_of::<Spi, _>().0);
+/// ```
+#[macro_export]
+macro_rules! chain_struct {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($chain:ty) {
+            $($(#[$field_attr:meta])* $field_vis:vis $field:ident),+ $(,)?
+        }
+    ) => {
+        $crate::__chain_struct!(
+            [$(#[$attr])*] $vis $name $chain;
+            [] $crate::Zero;
+            $($(#[$field_attr])* $field_vis $field),+
+        );
+
+        impl $crate::ChainStruct for $name {
+            type AsChain = $chain;
+
+            #[inline]
+            fn into_chain(self) -> $chain {
+                $crate::const_chain![$(self.$field),+]
+            }
+
+            #[inline]
+            fn from_chain(chain: $chain) -> Self {
+                $crate::__chain_struct!(@from chain; $($field)+)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement, ChainStruct, Link};
+
+    type Drivers = Link<u32, Link<u16, Chain<u8>>>;
+
+    chain_struct! {
+        #[derive(Debug, PartialEq)]
+        struct Board(Drivers) {
+            clock,
+            #[allow(dead_code)]
+            pub(crate) spi,
+            uart,
+        }
+    }
+
+    #[test]
+    pub fn test_chain_struct() {
+        let board = Board::from_chain(Chain::new(1u8).append(2u16).append(3u32));
+        assert_eq!(1, board.clock);
+        assert_eq!(3, board.uart);
+
+        let chain: Drivers = board.into_chain();
+        assert_eq!(2, *chain.at::<1>());
+        assert_eq!(
+            Board {
+                clock: 1,
+                spi: 2,
+                uart: 3
+            },
+            Board::from_chain(chain)
+        );
+    }
+
+    #[cfg(feature = "derive")]
+    #[derive(Chain, Debug, PartialEq)]
+    struct Named<T> {
+        first: u8,
+        second: T,
+        third: u32,
+    }
+
+    #[cfg(feature = "derive")]
+    #[derive(Chain, Debug, PartialEq)]
+    struct Tuple(u8, u16);
+
+    #[test]
+    #[cfg(feature = "derive")]
+    pub fn test_derive_named() {
+        let chain: Link<u32, Link<u16, Chain<u8>>> = Named {
+            first: 1,
+            second: 2u16,
+            third: 3,
+        }
+        .into_chain();
+
+        assert_eq!(1, *chain.at::<0>());
+        assert_eq!(3, *chain.at::<2>());
+
+        assert_eq!(
+            Named {
+                first: 1,
+                second: 2,
+                third: 3
+            },
+            Named::from(chain)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "derive")]
+    pub fn test_derive_tuple() {
+        let chain = Tuple(1, 2).into_chain();
+
+        assert_eq!(2, chain.len());
+        assert_eq!(Tuple(1, 2), Tuple::from_chain(chain));
+    }
+}
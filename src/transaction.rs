@@ -0,0 +1,116 @@
+//! All-or-nothing updates of chains.
+
+use core::ops::{Deref, DerefMut};
+
+/// A guard that restores a chain to a snapshot, unless the changes are committed.
+///
+/// Use [`ChainElement::transaction`] to create a guard. The guard dereferences to the chain, so it
+/// can be mutated as usual. If the guard is dropped without calling [`Transaction::commit`], e.g.
+/// because an update returned early with an error, the chain is restored to its state at the time
+/// the guard was created.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, Link};
+///
+/// type Settings = Link<u8, Chain<u32>>;
+///
+/// fn update(settings: &mut Settings, baud: u32, bits: u8) -> Result<(), &'static str> {
+///     let mut transaction = settings.transaction();
+///
+///     transaction.parent.object = baud;
+///     if !(5..=9).contains(&bits) {
+///         return Err("invalid word length");
+///     }
+///     transaction.object = bits;
+///
+///     transaction.commit();
+///     Ok(())
+/// }
+///
+/// let mut settings = Chain::new(115_200u32).append(8u8);
+///
+/// assert!(update(&mut settings, 9_600, 12).is_err());
+/// assert_eq!(115_200, settings.parent.object);
+///
+/// assert!(update(&mut settings, 9_600, 7).is_ok());
+/// assert_eq!(9_600, settings.parent.object);
+/// ```
+///
+/// [`ChainElement::transaction`]: crate::ChainElement::transaction
+pub struct Transaction<'a, C: Clone> {
+    chain: &'a mut C,
+    snapshot: Option<C>,
+}
+
+impl<'a, C: Clone> Transaction<'a, C> {
+    #[inline]
+    pub(crate) fn new(chain: &'a mut C) -> Self {
+        let snapshot = Some(chain.clone());
+        Self { chain, snapshot }
+    }
+
+    /// Keeps the changes made through the guard.
+    #[inline]
+    pub fn commit(mut self) {
+        self.snapshot = None;
+    }
+
+    /// Restores the chain to the snapshot.
+    ///
+    /// This is equivalent to dropping the guard.
+    #[inline]
+    pub fn rollback(self) {}
+}
+
+impl<C: Clone> Deref for Transaction<'_, C> {
+    type Target = C;
+
+    #[inline]
+    fn deref(&self) -> &C {
+        self.chain
+    }
+}
+
+impl<C: Clone> DerefMut for Transaction<'_, C> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut C {
+        self.chain
+    }
+}
+
+impl<C: Clone> Drop for Transaction<'_, C> {
+    #[inline]
+    fn drop(&mut self) {
+        if let Some(snapshot) = self.snapshot.take() {
+            *self.chain = snapshot;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement};
+
+    #[test]
+    pub fn test_transaction() {
+        let mut chain = Chain::new(1u8).append(2u16);
+
+        let mut transaction = chain.transaction();
+        transaction.object = 3;
+        transaction.parent.object = 4;
+        transaction.commit();
+        assert_eq!(Chain::new(4u8).append(3u16), chain);
+
+        let mut transaction = chain.transaction();
+        transaction.object = 5;
+        drop(transaction);
+        assert_eq!(Chain::new(4u8).append(3u16), chain);
+
+        let mut transaction = chain.transaction();
+        transaction.object = 6;
+        transaction.rollback();
+        assert_eq!(Chain::new(4u8).append(3u16), chain);
+    }
+}
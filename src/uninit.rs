@@ -0,0 +1,248 @@
+//! Initializing the objects of a chain one by one.
+
+use core::mem::{ManuallyDrop, MaybeUninit};
+
+use crate::{private, At, Chain, ChainElement, Const, Link, ToNat};
+
+/// Implemented by chains that can be built using [`ChainUninit`].
+///
+/// This trait is sealed, because [`ChainUninit`] relies on the memory layout of [`Chain`] and
+/// [`Link`].
+pub trait UninitChain: ChainElement + private::SealedLayout {
+    /// The same chain, with every object wrapped in a [`MaybeUninit`].
+    type Uninit: ChainElement;
+
+    /// Drops the objects whose bit is set in `initialized`, where bit `n` corresponds to the
+    /// object at position `n`.
+    ///
+    /// # Safety
+    ///
+    /// Every object whose bit is set must be initialized.
+    unsafe fn drop_initialized(uninit: &mut Self::Uninit, initialized: u32);
+}
+
+impl<V> UninitChain for Chain<V> {
+    type Uninit = Chain<MaybeUninit<V>>;
+
+    #[inline]
+    unsafe fn drop_initialized(uninit: &mut Self::Uninit, initialized: u32) {
+        if initialized & 1 != 0 {
+            uninit.object.assume_init_drop();
+        }
+    }
+}
+
+impl<V, C: UninitChain> UninitChain for Link<V, C> {
+    type Uninit = Link<MaybeUninit<V>, C::Uninit>;
+
+    #[inline]
+    unsafe fn drop_initialized(uninit: &mut Self::Uninit, initialized: u32) {
+        if initialized & (1 << C::LEN) != 0 {
+            uninit.object.assume_init_drop();
+        }
+        C::drop_initialized(&mut uninit.parent, initialized);
+    }
+}
+
+/// A chain whose objects are initialized one by one.
+///
+/// A `ChainUninit` can be created in const contexts, so it can be stored in a `static`. Objects
+/// can be initialized in any order, and the chain can be extracted once every object is
+/// initialized. Objects that are already initialized are dropped along with the builder.
+///
+/// Chains of at most 32 objects are supported.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{chain, Chain, ChainUninit, ChainElement, Link};
+///
+/// struct Clock(u32);
+/// struct Uart(u32);
+///
+/// let mut drivers = ChainUninit::<chain![Clock, Uart]>::new();
+///
+/// drivers.init::<0, _>(Clock(16_000_000));
+/// assert!(!drivers.is_initialized());
+///
+/// drivers.init::<1, _>(Uart(115_200));
+/// let drivers = drivers.finish();
+///
+/// assert_eq!(16_000_000, drivers.get_of::<Clock, _>().0);
+/// ```
+pub struct ChainUninit<C: UninitChain> {
+    chain: MaybeUninit<C>,
+    initialized: u32,
+}
+
+impl<C: UninitChain> ChainUninit<C> {
+    const ALL_INITIALIZED: u32 = if C::LEN == 32 {
+        u32::MAX
+    } else {
+        (1 << C::LEN) - 1
+    };
+
+    /// Creates a chain with every object uninitialized.
+    #[inline]
+    pub const fn new() -> Self {
+        const {
+            assert!(
+                C::LEN <= 32,
+                "ChainUninit supports chains of at most 32 objects"
+            )
+        };
+
+        Self {
+            chain: MaybeUninit::uninit(),
+            initialized: 0,
+        }
+    }
+
+    #[inline]
+    fn uninit_mut(&mut self) -> &mut C::Uninit {
+        // Safety: `Chain` and `Link` are `repr(C)`, and `MaybeUninit<T>` has the same layout as
+        // `T`, so `C::Uninit` has the same layout as `C`. Every object of `C::Uninit` is a
+        // `MaybeUninit`, which doesn't require initialization.
+        unsafe { &mut *(self.chain.as_mut_ptr() as *mut C::Uninit) }
+    }
+
+    /// Initializes the object at position `N`, dropping the previous object if it was already
+    /// initialized.
+    #[inline]
+    pub fn init<const N: usize, T>(&mut self, value: T)
+    where
+        Const<N>: ToNat,
+        C::Uninit: At<<Const<N> as ToNat>::Nat, Output = MaybeUninit<T>>,
+    {
+        let initialized = self.initialized & (1 << N) != 0;
+        let object = self.uninit_mut().at_position_mut();
+        if initialized {
+            // Safety: the bit is only set after the object is written.
+            unsafe { object.assume_init_drop() };
+        }
+
+        object.write(value);
+        self.initialized |= 1 << N;
+    }
+
+    /// Returns whether the object at position `N` is initialized.
+    #[inline]
+    pub fn is_init<const N: usize>(&self) -> bool
+    where
+        Const<N>: ToNat,
+        C::Uninit: At<<Const<N> as ToNat>::Nat>,
+    {
+        self.initialized & (1 << N) != 0
+    }
+
+    /// Returns whether every object is initialized.
+    #[inline]
+    pub fn is_initialized(&self) -> bool {
+        self.initialized == Self::ALL_INITIALIZED
+    }
+
+    /// Returns the initialized chain, or the builder itself if some objects are not initialized.
+    #[inline]
+    pub fn try_finish(self) -> Result<C, Self> {
+        if !self.is_initialized() {
+            return Err(self);
+        }
+
+        let this = ManuallyDrop::new(self);
+
+        // Safety: every object is initialized, and `this` is not dropped, so the objects are moved
+        // out exactly once.
+        Ok(unsafe { this.chain.assume_init_read() })
+    }
+
+    /// Returns the initialized chain.
+    ///
+    /// # Panics
+    ///
+    /// Panics if some objects are not initialized.
+    #[inline]
+    pub fn finish(self) -> C {
+        match self.try_finish() {
+            Ok(chain) => chain,
+            Err(_) => panic!("Not every object of the chain is initialized"),
+        }
+    }
+}
+
+impl<C: UninitChain> Default for ChainUninit<C> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C: UninitChain> Drop for ChainUninit<C> {
+    fn drop(&mut self) {
+        // Safety: bits are only set for initialized objects.
+        let initialized = self.initialized;
+        unsafe { C::drop_initialized(self.uninit_mut(), initialized) };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::cell::Cell;
+
+    use crate::{chain, Chain, ChainElement, ChainUninit, Link};
+
+    struct DropCounter<'a>(&'a Cell<u32>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    pub fn test_staged_init() {
+        let mut chain = ChainUninit::<chain![u8, u16, u32]>::new();
+
+        chain.init::<2, _>(3);
+        chain.init::<0, _>(1);
+        assert!(chain.is_init::<0>());
+        assert!(!chain.is_init::<1>());
+
+        let Err(mut chain) = chain.try_finish() else {
+            panic!("Chain should not be fully initialized");
+        };
+        chain.init::<1, _>(2);
+
+        let chain = chain.finish();
+        assert_eq!(1, *chain.at::<0>());
+        assert_eq!(2, *chain.at::<1>());
+        assert_eq!(3, *chain.at::<2>());
+    }
+
+    #[test]
+    pub fn test_drop_initialized() {
+        let drops = Cell::new(0);
+
+        let mut chain = ChainUninit::<chain![DropCounter, DropCounter, DropCounter]>::new();
+        chain.init::<0, _>(DropCounter(&drops));
+        chain.init::<2, _>(DropCounter(&drops));
+        chain.init::<2, _>(DropCounter(&drops));
+        assert_eq!(1, drops.get());
+
+        drop(chain);
+        assert_eq!(3, drops.get());
+    }
+
+    #[test]
+    pub fn test_finish_does_not_drop() {
+        let drops = Cell::new(0);
+
+        let mut chain = ChainUninit::<chain![DropCounter]>::new();
+        chain.init::<0, _>(DropCounter(&drops));
+
+        let chain = chain.finish();
+        assert_eq!(0, drops.get());
+
+        drop(chain);
+        assert_eq!(1, drops.get());
+    }
+}
@@ -0,0 +1,336 @@
+//! Unrolling code for every object of a chain.
+
+/// Lists the indices of a chain of the given length, starting with the last object's index, and
+/// passes the result to `$callback`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __indices {
+    (1, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [0] $($args)* }
+    };
+    (2, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [1 0] $($args)* }
+    };
+    (3, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [2 1 0] $($args)* }
+    };
+    (4, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [3 2 1 0] $($args)* }
+    };
+    (5, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [4 3 2 1 0] $($args)* }
+    };
+    (6, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [5 4 3 2 1 0] $($args)* }
+    };
+    (7, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [6 5 4 3 2 1 0] $($args)* }
+    };
+    (8, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (9, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (10, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (11, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (12, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (13, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (14, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (15, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (16, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (17, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (18, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (19, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (20, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (21, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (22, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [21 20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (23, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [22 21 20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (24, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [23 22 21 20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (25, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [24 23 22 21 20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (26, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [25 24 23 22 21 20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (27, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [26 25 24 23 22 21 20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (28, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [27 26 25 24 23 22 21 20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (29, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [28 27 26 25 24 23 22 21 20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (30, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [29 28 27 26 25 24 23 22 21 20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (31, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [30 29 28 27 26 25 24 23 22 21 20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+    (32, $callback:ident, $($args:tt)*) => {
+        $crate::$callback! { [31 30 29 28 27 26 25 24 23 22 21 20 19 18 17 16 15 14 13 12 11 10 9 8 7 6 5 4 3 2 1 0] $($args)* }
+    };
+}
+
+/// Implementation of `for_each_link!`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __for_each_link {
+    ([$index:tt] $mode:tt [$($path:tt)+] |$elem:pat_param| $body:expr) => {
+        $crate::__for_each_link!(@visit $mode [$($path)+] |$elem| $body);
+    };
+    ([$index:tt $($rest:tt)+] $mode:tt [$($path:tt)+] |$elem:pat_param| $body:expr) => {
+        $crate::__for_each_link!([$($rest)+] $mode [$($path)+ .parent] |$elem| $body);
+        $crate::__for_each_link!(@visit $mode [$($path)+] |$elem| $body);
+    };
+    (@visit mut [$($path:tt)+] |$elem:pat_param| $body:expr) => {{
+        let $elem = &mut $($path)+.object;
+        $body;
+    }};
+    (@visit ref [$($path:tt)+] |$elem:pat_param| $body:expr) => {{
+        let $elem = &$($path)+.object;
+        $body;
+    }};
+}
+
+/// Runs a block of code for every object of a chain, in the order they were appended.
+///
+/// The macro is unrolled at compile time, so the block is compiled separately for every object,
+/// with the element bound to a reference of the object's concrete type. This means that no trait
+/// objects are involved and every call can be inlined.
+///
+/// Because the length of the chain can't be known while the macro is expanded, it has to be
+/// specified as an integer literal between 1 and 32. The length is checked at compile time.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{for_each_link, Chain, ChainElement};
+///
+/// trait Init {
+///     fn init(&mut self);
+/// }
+///
+/// struct Uart(bool);
+/// struct Spi(bool);
+///
+/// impl Init for Uart {
+///     fn init(&mut self) {
+///         self.0 = true;
+///     }
+/// }
+///
+/// impl Init for Spi {
+///     fn init(&mut self) {
+///         self.0 = true;
+///     }
+/// }
+///
+/// let mut chain = Chain::new(Uart(false)).append(Spi(false));
+///
+/// for_each_link!(&mut chain, 2, |elem| elem.init());
+///
+/// let mut initialized = 0;
+/// for_each_link!(&chain, 2, |elem| if elem.0 {
+///     initialized += 1;
+/// });
+/// assert_eq!(2, initialized);
+/// ```
+#[macro_export]
+macro_rules! for_each_link {
+    (&mut $chain:expr, $len:tt, |$elem:pat_param| $body:expr) => {{
+        let chain = &mut $chain;
+        $crate::__private::assert_exact_length::<_, $len>(&*chain);
+        $crate::__indices!($len, __for_each_link, mut [chain] |$elem| $body);
+    }};
+    (&$chain:expr, $len:tt, |$elem:pat_param| $body:expr) => {{
+        let chain = &$chain;
+        $crate::__private::assert_exact_length::<_, $len>(chain);
+        $crate::__indices!($len, __for_each_link, ref [chain] |$elem| $body);
+    }};
+}
+
+/// Implementation of `static_dispatch!`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __static_dispatch {
+    ([$index:tt $($rest:tt)*] $mode:tt [$($path:tt)+] $selector:tt |$elem:pat_param| $body:expr; $($arms:tt)*) => {
+        $crate::__static_dispatch!(
+            [$($rest)*] $mode [$($path)+ .parent] $selector |$elem| $body;
+            $($arms)*
+            $index => $crate::__static_dispatch!(@arm $mode [$($path)+] |$elem| $body),
+        )
+    };
+    ([] $mode:tt [$($path:tt)+] [$selector:expr] |$elem:pat_param| $body:expr; $($arms:tt)*) => {
+        match $selector {
+            $($arms)*
+            _ => None,
+        }
+    };
+    (@arm mut [$($path:tt)+] |$elem:pat_param| $body:expr) => {{
+        let $elem = &mut $($path)+.object;
+        Some($body)
+    }};
+    (@arm ref [$($path:tt)+] |$elem:pat_param| $body:expr) => {{
+        let $elem = &$($path)+.object;
+        Some($body)
+    }};
+}
+
+/// Runs an expression on the object at a runtime index, in the order the objects were appended.
+///
+/// The macro expands to a `match` on the index, where every arm binds the element to a reference
+/// of the object's concrete type. This means that no trait objects are involved, and methods
+/// that are not object safe (e.g. generic methods) can be called as well.
+///
+/// The macro returns `Some` with the value of the expression, or `None` if the index is out of
+/// bounds. Just like with [`for_each_link!`], the length of the chain has to be specified as an
+/// integer literal between 1 and 32, and the length is checked at compile time.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{static_dispatch, Chain, ChainElement};
+///
+/// trait Encode {
+///     fn encode<const N: usize>(&self) -> [u8; N];
+/// }
+///
+/// struct Temperature(u8);
+/// struct Humidity(u8);
+///
+/// impl Encode for Temperature {
+///     fn encode<const N: usize>(&self) -> [u8; N] {
+///         [self.0; N]
+///     }
+/// }
+///
+/// impl Encode for Humidity {
+///     fn encode<const N: usize>(&self) -> [u8; N] {
+///         [self.0 + 1; N]
+///     }
+/// }
+///
+/// let chain = Chain::new(Temperature(20)).append(Humidity(40));
+///
+/// assert_eq!(Some([41, 41]), static_dispatch!(&chain, 2, 1, |elem| elem.encode::<2>()));
+/// assert_eq!(None, static_dispatch!(&chain, 2, 2, |elem| elem.encode::<2>()));
+/// ```
+///
+/// [`for_each_link!`]: crate::for_each_link
+#[macro_export]
+macro_rules! static_dispatch {
+    (&mut $chain:expr, $len:tt, $index:expr, |$elem:pat_param| $body:expr) => {{
+        let chain = &mut $chain;
+        $crate::__private::assert_exact_length::<_, $len>(&*chain);
+        $crate::__indices!($len, __static_dispatch, mut [chain] [$index] |$elem| $body;)
+    }};
+    (&$chain:expr, $len:tt, $index:expr, |$elem:pat_param| $body:expr) => {{
+        let chain = &$chain;
+        $crate::__private::assert_exact_length::<_, $len>(chain);
+        $crate::__indices!($len, __static_dispatch, ref [chain] [$index] |$elem| $body;)
+    }};
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Chain, ChainElement};
+
+    trait Describe {
+        fn describe(&self) -> u32;
+
+        fn bump(&mut self);
+    }
+
+    impl Describe for u8 {
+        fn describe(&self) -> u32 {
+            *self as u32
+        }
+
+        fn bump(&mut self) {
+            *self += 1;
+        }
+    }
+
+    impl Describe for u32 {
+        fn describe(&self) -> u32 {
+            *self * 10
+        }
+
+        fn bump(&mut self) {
+            *self += 2;
+        }
+    }
+
+    #[test]
+    pub fn test_for_each_link() {
+        let mut chain = Chain::new(1u8).append(2u32).append(3u8);
+
+        for_each_link!(&mut chain, 3, |elem| elem.bump());
+
+        let mut visited = [0; 3];
+        let mut index = 0;
+        for_each_link!(&chain, 3, |elem| {
+            visited[index] = elem.describe();
+            index += 1;
+        });
+
+        assert_eq!(3, index);
+        assert_eq!([2, 40, 4], visited);
+    }
+
+    #[test]
+    pub fn test_for_each_link_single() {
+        let chain = Chain::new(5u8);
+
+        let mut sum = 0;
+        for_each_link!(&chain, 1, |elem| sum += elem.describe());
+
+        assert_eq!(5, sum);
+    }
+
+    #[test]
+    pub fn test_static_dispatch() {
+        let mut chain = Chain::new(1u8).append(2u32).append(3u8);
+
+        assert_eq!(
+            Some(()),
+            static_dispatch!(&mut chain, 3, 1, |elem| elem.bump())
+        );
+
+        let described =
+            [0, 1, 2, 3].map(|index| static_dispatch!(&chain, 3, index, |elem| elem.describe()));
+        assert_eq!([Some(1), Some(40), Some(3), None], described);
+    }
+}
@@ -0,0 +1,102 @@
+//! Dispatching positioned events to a stack of widgets.
+
+use crate::{Chain, ChainElement, Link};
+
+/// A user interface element that reacts to positioned events, e.g. touches.
+pub trait Widget<E> {
+    /// Returns whether the position of `event` is inside the widget.
+    fn hit_test(&self, event: &E) -> bool;
+
+    /// Handles `event`, whose position is inside the widget.
+    ///
+    /// Returns `true` if the widget has consumed the event. Otherwise, the event is passed on to
+    /// the widget below.
+    fn handle_event(&mut self, event: &E) -> bool;
+}
+
+/// A chain of [`Widget`] objects, stacked in the order they were appended.
+///
+/// The first appended widget is at the bottom of the stack, and the last appended one is on top.
+pub trait WidgetChain<E>: ChainElement {
+    /// Passes `event` to the widgets under its position, starting from the top, until one of them
+    /// consumes it.
+    ///
+    /// Returns the index of the widget that consumed the event, or `None` if no widget did.
+    fn dispatch_event(&mut self, event: &E) -> Option<usize>;
+}
+
+impl<E, W: Widget<E>> WidgetChain<E> for Chain<W> {
+    #[inline]
+    fn dispatch_event(&mut self, event: &E) -> Option<usize> {
+        if self.object.hit_test(event) && self.object.handle_event(event) {
+            Some(0)
+        } else {
+            None
+        }
+    }
+}
+
+impl<E, W: Widget<E>, C: WidgetChain<E>> WidgetChain<E> for Link<W, C> {
+    #[inline]
+    fn dispatch_event(&mut self, event: &E) -> Option<usize> {
+        if self.object.hit_test(event) && self.object.handle_event(event) {
+            Some(C::LEN)
+        } else {
+            self.parent.dispatch_event(event)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct Touch {
+        x: i32,
+    }
+
+    /// Covers `from..to` and consumes events if `opaque`.
+    struct Panel {
+        from: i32,
+        to: i32,
+        opaque: bool,
+        touched: u32,
+    }
+
+    impl Panel {
+        fn new(from: i32, to: i32, opaque: bool) -> Self {
+            Self {
+                from,
+                to,
+                opaque,
+                touched: 0,
+            }
+        }
+    }
+
+    impl Widget<Touch> for Panel {
+        fn hit_test(&self, event: &Touch) -> bool {
+            (self.from..self.to).contains(&event.x)
+        }
+
+        fn handle_event(&mut self, _event: &Touch) -> bool {
+            self.touched += 1;
+            self.opaque
+        }
+    }
+
+    #[test]
+    pub fn test_dispatch_event() {
+        let mut widgets = Chain::new(Panel::new(0, 100, true))
+            .append(Panel::new(10, 20, true))
+            .append(Panel::new(50, 60, false));
+
+        assert_eq!(Some(1), widgets.dispatch_event(&Touch { x: 15 }));
+        assert_eq!(Some(0), widgets.dispatch_event(&Touch { x: 55 }));
+        assert_eq!(None, widgets.dispatch_event(&Touch { x: 200 }));
+
+        assert_eq!(1, widgets.parent.parent.object.touched);
+        assert_eq!(1, widgets.parent.object.touched);
+        assert_eq!(1, widgets.object.touched);
+    }
+}
@@ -0,0 +1,77 @@
+//! Wrapping every object of a chain.
+
+use core::cell::RefCell;
+
+use crate::{Chain, ChainElement, Link};
+
+/// Describes a wrapper type that can be applied to every object of a chain using
+/// [`ChainElement::wrap_each`].
+///
+/// This trait is implemented by marker types, like [`InRefCell`].
+pub trait Wrapper {
+    /// The wrapper type for an object of type `T`.
+    type Wrapped<T>;
+
+    /// Wraps an object.
+    fn wrap<T>(object: T) -> Self::Wrapped<T>;
+}
+
+/// Wraps every object into a [`RefCell`].
+pub struct InRefCell;
+
+impl Wrapper for InRefCell {
+    type Wrapped<T> = RefCell<T>;
+
+    #[inline]
+    fn wrap<T>(object: T) -> RefCell<T> {
+        RefCell::new(object)
+    }
+}
+
+/// Implemented by chains whose objects can be wrapped using a [`Wrapper`].
+pub trait WrapEach: ChainElement {
+    /// The chain with every object wrapped by `W`.
+    type Wrapped<W: Wrapper>: ChainElement;
+
+    /// Wraps every object of the chain using `W`.
+    fn wrap_each_with<W: Wrapper>(self) -> Self::Wrapped<W>;
+}
+
+impl<V> WrapEach for Chain<V> {
+    type Wrapped<W: Wrapper> = Chain<W::Wrapped<V>>;
+
+    #[inline]
+    fn wrap_each_with<W: Wrapper>(self) -> Self::Wrapped<W> {
+        Chain::new(W::wrap(self.object))
+    }
+}
+
+impl<V, C: WrapEach> WrapEach for Link<V, C> {
+    type Wrapped<W: Wrapper> = Link<W::Wrapped<V>, C::Wrapped<W>>;
+
+    #[inline]
+    fn wrap_each_with<W: Wrapper>(self) -> Self::Wrapped<W> {
+        Link {
+            parent: self.parent.wrap_each_with::<W>(),
+            object: W::wrap(self.object),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::cell::RefCell;
+
+    use crate::{chain, Chain, ChainElement, InRefCell, Link};
+
+    #[test]
+    pub fn test_wrap_each() {
+        let chain: chain![RefCell<u8>, RefCell<u16>] =
+            Chain::new(1u8).append(2u16).wrap_each::<InRefCell>();
+
+        *chain.borrow_mut_of::<u16, _>() += 1;
+
+        assert_eq!(1, *chain.borrow_of::<u8, _>());
+        assert_eq!(3, *chain.object.borrow());
+    }
+}
@@ -0,0 +1,222 @@
+//! Combining two chains object by object.
+
+use crate::{Chain, ChainElement, Link};
+
+/// Combines an object of type `A` with an object of type `B`.
+///
+/// The objects of two chains usually have different types, so a combiner implements this trait
+/// once for every pair of types it can combine. This trait is also implemented for closures, which
+/// can be used if every pair has the same types.
+pub trait Combine<A, B> {
+    /// The type of the combined object.
+    type Output;
+
+    /// Combines `a` and `b`.
+    fn combine(&mut self, a: A, b: B) -> Self::Output;
+}
+
+impl<A, B, O, F: FnMut(A, B) -> O> Combine<A, B> for F {
+    type Output = O;
+
+    #[inline]
+    fn combine(&mut self, a: A, b: B) -> O {
+        self(a, b)
+    }
+}
+
+/// Combines two objects into a tuple. Used by [`ChainElement::zip`].
+pub struct Pair;
+
+impl<A, B> Combine<A, B> for Pair {
+    type Output = (A, B);
+
+    #[inline]
+    fn combine(&mut self, a: A, b: B) -> (A, B) {
+        (a, b)
+    }
+}
+
+/// Calls a function with an argument. Used by [`ChainElement::apply`].
+pub struct Call;
+
+impl<A, O, F: FnOnce(A) -> O> Combine<F, A> for Call {
+    type Output = O;
+
+    #[inline]
+    fn combine(&mut self, f: F, arg: A) -> O {
+        f(arg)
+    }
+}
+
+/// Implemented by chains that can be combined with the chain `Rhs` of the same length by the
+/// combiner `F`.
+///
+/// # Example:
+///
+/// ```rust
+/// use object_chain::{Chain, ChainElement, Combine};
+///
+/// struct Offset(i16);
+/// struct Scale(u16);
+///
+/// struct Calibrate;
+///
+/// impl Combine<Offset, i16> for Calibrate {
+///     type Output = i16;
+///
+///     fn combine(&mut self, calibration: Offset, raw: i16) -> i16 {
+///         raw + calibration.0
+///     }
+/// }
+///
+/// impl Combine<Scale, u16> for Calibrate {
+///     type Output = u32;
+///
+///     fn combine(&mut self, calibration: Scale, raw: u16) -> u32 {
+///         raw as u32 * calibration.0 as u32
+///     }
+/// }
+///
+/// let calibration = Chain::new(Offset(-5)).append(Scale(10));
+/// let readings = Chain::new(105i16).append(42u16);
+///
+/// let calibrated = calibration.zip_with(readings, Calibrate);
+///
+/// assert_eq!(100, calibrated.parent.object);
+/// assert_eq!(420, calibrated.object);
+/// ```
+pub trait ZipWith<Rhs, F>: ChainElement {
+    /// The chain of combined objects.
+    type Zipped: ChainElement;
+
+    /// Combines the objects of the two chains at the same positions, in the order they were
+    /// appended.
+    fn zip_with_combiner(self, other: Rhs, combiner: &mut F) -> Self::Zipped;
+}
+
+impl<A, B, F: Combine<A, B>> ZipWith<Chain<B>, F> for Chain<A> {
+    type Zipped = Chain<F::Output>;
+
+    #[inline]
+    fn zip_with_combiner(self, other: Chain<B>, combiner: &mut F) -> Self::Zipped {
+        Chain::new(combiner.combine(self.object, other.object))
+    }
+}
+
+impl<A, B, C, D, F> ZipWith<Link<B, D>, F> for Link<A, C>
+where
+    F: Combine<A, B>,
+    C: ZipWith<D, F>,
+    D: ChainElement,
+{
+    type Zipped = Link<F::Output, C::Zipped>;
+
+    #[inline]
+    fn zip_with_combiner(self, other: Link<B, D>, combiner: &mut F) -> Self::Zipped {
+        let parent = self.parent.zip_with_combiner(other.parent, combiner);
+        Link {
+            parent,
+            object: combiner.combine(self.object, other.object),
+        }
+    }
+}
+
+/// Implemented by chains of pairs, which can be split into a chain of the first elements and a
+/// chain of the second elements.
+///
+/// This is the inverse of [`ChainElement::zip`].
+pub trait Unzip: ChainElement {
+    /// The chain of the first elements of the pairs.
+    type Left: ChainElement;
+
+    /// The chain of the second elements of the pairs.
+    type Right: ChainElement;
+
+    /// Splits the chain of pairs into two chains.
+    fn unzip_pairs(self) -> (Self::Left, Self::Right);
+}
+
+impl<A, B> Unzip for Chain<(A, B)> {
+    type Left = Chain<A>;
+    type Right = Chain<B>;
+
+    #[inline]
+    fn unzip_pairs(self) -> (Chain<A>, Chain<B>) {
+        let (a, b) = self.object;
+        (Chain::new(a), Chain::new(b))
+    }
+}
+
+impl<A, B, C: Unzip> Unzip for Link<(A, B), C> {
+    type Left = Link<A, C::Left>;
+    type Right = Link<B, C::Right>;
+
+    #[inline]
+    fn unzip_pairs(self) -> (Self::Left, Self::Right) {
+        let (left, right) = self.parent.unzip_pairs();
+        let (a, b) = self.object;
+        (
+            Link {
+                parent: left,
+                object: a,
+            },
+            Link {
+                parent: right,
+                object: b,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{assert_chain_eq, Chain, ChainElement};
+
+    #[test]
+    pub fn test_zip_with_closure() {
+        let a = Chain::new(1u8).append(2).append(3);
+        let b = Chain::new(10u8).append(20).append(30);
+
+        let mut calls = 0;
+        let sums = a.zip_with(b, |a, b| {
+            calls += 1;
+            a + b
+        });
+
+        assert_eq!(3, calls);
+        assert_chain_eq!(sums, (11, 22, 33));
+    }
+
+    #[test]
+    pub fn test_apply() {
+        fn double(x: u8) -> u16 {
+            x as u16 * 2
+        }
+
+        let prefix = "value: ";
+        let functions = Chain::new(double)
+            .append(|s: &str| s.len())
+            .append(move |x: u32| (prefix, x));
+
+        let results = functions.apply(Chain::new(200).append("four").append(5));
+
+        assert_chain_eq!(results, (400, 4, ("value: ", 5)));
+    }
+
+    #[test]
+    pub fn test_zip() {
+        let zipped = Chain::new(1u8)
+            .append('b')
+            .zip(Chain::new("one").append(2.0));
+
+        assert_chain_eq!(zipped, ((1, "one"), ('b', 2.0)));
+    }
+
+    #[test]
+    pub fn test_unzip() {
+        let (drivers, configs) = Chain::new((1u8, "uart")).append(('s', 2.0)).unzip();
+
+        assert_chain_eq!(drivers, (1, 's'));
+        assert_chain_eq!(configs, ("uart", 2.0));
+    }
+}